@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use antler::prelude::TileSelection;
+
+pub const USAGE: &str = "\
+Usage:
+  antler [render] [MANIFEST] [--tile-range START..END | --shard INDEX/COUNT]
+  antler merge [--output-dir DIR] SHARD...
+  antler serve [--address ADDRESS]
+
+Options:
+  --tile-range START..END  Render tiles START (inclusive) to END (exclusive) into shard files
+  --shard INDEX/COUNT      Render the INDEX-th of COUNT equal tile ranges (1-based) into shard files
+  --output-dir DIR         Directory to write merged images to [default: output]
+  --address ADDRESS        Address for the render server to listen on [default: 127.0.0.1:7878]";
+
+const DEFAULT_MANIFEST: &str = "input/test.ron";
+const DEFAULT_MERGE_DIR: &str = "output";
+const DEFAULT_SERVE_ADDRESS: &str = "127.0.0.1:7878";
+
+pub enum Command {
+    Render {
+        manifest: PathBuf,
+        selection: TileSelection,
+    },
+    Merge {
+        output_dir: PathBuf,
+        shards: Vec<PathBuf>,
+    },
+    Serve {
+        address: String,
+    },
+    Help,
+}
+
+impl Command {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter().peekable();
+
+        match args.peek().map(String::as_str) {
+            Some("merge") => {
+                args.next();
+                Self::parse_merge(args)
+            }
+            Some("serve") => {
+                args.next();
+                Self::parse_serve(args)
+            }
+            Some("render") => {
+                args.next();
+                Self::parse_render(args)
+            }
+            _ => Self::parse_render(args),
+        }
+    }
+
+    fn parse_render(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut manifest = None;
+        let mut selection = TileSelection::All;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(Self::Help),
+                "--tile-range" => selection = parse_tile_range(&expect_value(&mut args, &arg)?)?,
+                "--shard" => selection = parse_shard(&expect_value(&mut args, &arg)?)?,
+                flag if flag.starts_with('-') => return Err(format!("Unknown option '{flag}'\n\n{USAGE}")),
+                _ if manifest.is_none() => manifest = Some(PathBuf::from(arg)),
+                _ => return Err(format!("Unexpected argument '{arg}'\n\n{USAGE}")),
+            }
+        }
+
+        Ok(Self::Render {
+            manifest: manifest.unwrap_or_else(|| PathBuf::from(DEFAULT_MANIFEST)),
+            selection,
+        })
+    }
+
+    fn parse_merge(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut output_dir = PathBuf::from(DEFAULT_MERGE_DIR);
+        let mut shards = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(Self::Help),
+                "--output-dir" => output_dir = PathBuf::from(expect_value(&mut args, &arg)?),
+                flag if flag.starts_with('-') => return Err(format!("Unknown option '{flag}'\n\n{USAGE}")),
+                _ => shards.push(PathBuf::from(arg)),
+            }
+        }
+
+        if shards.is_empty() {
+            return Err(format!("No shard files given to merge\n\n{USAGE}"));
+        }
+
+        Ok(Self::Merge { output_dir, shards })
+    }
+
+    fn parse_serve(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut address = String::from(DEFAULT_SERVE_ADDRESS);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(Self::Help),
+                "--address" => address = expect_value(&mut args, &arg)?,
+                _ => return Err(format!("Unexpected argument '{arg}'\n\n{USAGE}")),
+            }
+        }
+
+        Ok(Self::Serve { address })
+    }
+}
+
+fn expect_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("Option '{flag}' requires a value"))
+}
+
+fn parse_tile_range(value: &str) -> Result<TileSelection, String> {
+    let invalid = || format!("Invalid tile range '{value}', expected START..END");
+
+    let (start, end) = value.split_once("..").ok_or_else(invalid)?;
+    let start = start.trim().parse::<usize>().map_err(|_| invalid())?;
+    let end = end.trim().parse::<usize>().map_err(|_| invalid())?;
+
+    if start >= end {
+        return Err(format!("Tile range '{value}' is empty"));
+    }
+
+    Ok(TileSelection::Range(start..end))
+}
+
+fn parse_shard(value: &str) -> Result<TileSelection, String> {
+    let invalid = || format!("Invalid shard '{value}', expected INDEX/COUNT");
+
+    let (index, count) = value.split_once('/').ok_or_else(invalid)?;
+    let index = index.trim().parse::<usize>().map_err(|_| invalid())?;
+    let count = count.trim().parse::<usize>().map_err(|_| invalid())?;
+
+    if index == 0 || index > count {
+        return Err(format!("Shard index in '{value}' must be between 1 and {count}"));
+    }
+
+    Ok(TileSelection::Shard {
+        index: index - 1,
+        count,
+    })
+}
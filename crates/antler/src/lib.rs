@@ -1,15 +1,30 @@
 pub mod prelude {
-    pub use antler_camera::{Camera, Observer, Orthographic, Perspective};
+    pub use antler_camera::{
+        Aperture, Camera, CameraRay, Convergence, Cubemap, CubemapLayout, Distortion, Equirectangular, Exposure,
+        Fisheye, FisheyeProjection, Framing, Lens, MotionBlur, Observer, Optics, Orthographic, Perspective, Stereo,
+        StereoLayout,
+    };
     pub use antler_colour::{Rgb, Rgba};
     pub use antler_config::Manifest;
     pub use antler_geometry::{
-        Aabb, Bounded, Capsule, Circle, Contact, Mesh, Quad, Ray, Sphere, Torus, Traceable, Triangle,
+        Aabb, Annulus, Bounded, Capsule, Circle, Cone, Contact, Csg, CsgOperation, Cylinder, Geometry, Heightfield,
+        Mesh, MeshBuffers, MeshRepair, Quad, Ray, RepairSummary, Sdf, SdfNode, SdfPrimitive, Sphere, Torus, Traceable,
+        Triangle, VertexAttribute,
     };
-    pub use antler_image::{RgbImage, RgbaImage};
+    pub use antler_gltf::Gltf;
+    pub use antler_image::{PngRowWriter, RgbImage, RgbaImage, Tile, load_height_map};
     pub use antler_light::{Directional, Light};
     pub use antler_material::{Ggx, Lambertian, Material, Mirror, Opaque, Reflective, Refractive};
-    pub use antler_render::{render_image, render_probe, render_tile};
+    pub use antler_parameters::{CaptureParameters, SceneParameters, SimulationParameters};
+    pub use antler_render::{
+        CancelToken, Progress, ProgressReport, RenderOptions, RenderOutput, RenderStatus, RenderedTile, Shard, Silent,
+        TerminalProgress, TileSelection, compose_images, render_image, render_image_streaming, render_probe,
+        render_tile, render_tiles,
+    };
     pub use antler_scene::{Object, Resources, Scene};
-    pub use antler_settings::{ImageSettings, ProbeSettings};
+    pub use antler_server::Server;
+    pub use antler_settings::{
+        ContactSheet, Crop, CropOutput, CropWindow, ImageSettings, ProbeSettings, Sequence, SequenceAnimation,
+    };
     pub use antler_shader::{Block, Checkerboard, Luminous, Solid};
 }
@@ -1,43 +1,301 @@
-use std::{error::Error, fs::create_dir_all};
+mod cli;
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fs::create_dir_all,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
 use antler::prelude::*;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let manifest = Manifest::load("input/test.ron")?;
-    let parameters = manifest.build()?;
+use crate::cli::{Command, USAGE};
+
+fn main() -> ExitCode {
+    if let Err(err) = run() {
+        eprintln!("Error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    match Command::parse(std::env::args().skip(1))? {
+        Command::Render { manifest, selection } => render(&manifest, &selection),
+        Command::Merge { output_dir, shards } => merge(&output_dir, &shards),
+        Command::Serve { address } => serve(&address),
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(())
+        }
+    }
+}
+
+fn render(manifest_path: &Path, selection: &TileSelection) -> Result<(), Box<dyn Error>> {
+    let manifest = Manifest::load(manifest_path)?;
+    let fingerprint = manifest.fingerprint()?;
+    let (mut parameters, animation) = manifest.build_animated()?;
+    report_repairs(&parameters.resources);
 
     // Create output directory if it doesn't exist
     create_dir_all(&parameters.output_dir)?;
 
-    for (scene_name, scene) in parameters.scenes {
+    let Some(frames) = animation.frames() else {
+        return render_scenes(&parameters, None, fingerprint, selection);
+    };
+
+    for frame in frames {
+        println!("> Frame: {frame}");
+
+        animation.apply(frame, &mut parameters)?;
+        render_scenes(&parameters, Some(frame), fingerprint, selection)?;
+    }
+
+    Ok(())
+}
+
+fn report_repairs(resources: &Resources) {
+    for geometry in resources.geometries() {
+        if let Geometry::Mesh(mesh) = geometry
+            && let Some((path, summary)) = mesh.repair_summary()
+        {
+            println!("> Mesh: {} ({summary})", path.display());
+        }
+    }
+}
+
+fn render_scenes(
+    parameters: &SimulationParameters,
+    frame: Option<usize>,
+    fingerprint: u64,
+    selection: &TileSelection,
+) -> Result<(), Box<dyn Error>> {
+    for (scene_name, scene) in &parameters.scenes {
         println!("> Scene: {scene_name}");
-        for (capture_name, capture) in scene.captures {
+        for (capture_name, capture) in &scene.captures {
             println!("  > Capture: {capture_name}");
-            for (image_name, image) in capture.images {
+            for (image_name, image) in &capture.images {
                 println!("    > Image: {image_name}");
 
-                let (visual_image, temporal_image) = render_image(
-                    &image,
-                    &parameters.lighting_settings,
-                    &parameters.probe_settings,
-                    &capture.camera,
-                    &parameters.resources,
-                    &scene.scene,
-                );
-
-                let visual_image_path = parameters
-                    .output_dir
-                    .join(format!("{scene_name}-{capture_name}-{image_name}.png"));
-                // visual_image.tone_map();
-                visual_image.save(&visual_image_path)?;
-
-                let temporal_image_path = parameters
-                    .output_dir
-                    .join(format!("{scene_name}-{capture_name}-{image_name}-temporal.png"));
-                temporal_image.save(&temporal_image_path)?;
+                let name = format!("{scene_name}-{capture_name}-{image_name}");
+                let name = match frame {
+                    Some(frame) => Sequence::frame_name(&name, frame),
+                    None => name,
+                };
+
+                let Some(sequence) = capture.sequence else {
+                    render_frame(
+                        parameters,
+                        &scene.scene,
+                        &capture.cameras[0],
+                        image,
+                        &name,
+                        fingerprint,
+                        selection,
+                    )?;
+                    continue;
+                };
+
+                for (orbit_frame, camera) in capture.cameras.iter().enumerate() {
+                    println!("      > Orbit frame {}/{}", orbit_frame + 1, capture.cameras.len());
+
+                    let frame_name = Sequence::frame_name(&name, orbit_frame);
+                    render_frame(
+                        parameters,
+                        &scene.scene,
+                        camera,
+                        image,
+                        &frame_name,
+                        fingerprint,
+                        selection,
+                    )?;
+                }
+
+                // Shards hold only part of each frame, so sequences are assembled once they are merged and rendered.
+                if *selection == TileSelection::All {
+                    assemble_sequence(&sequence, &parameters.output_dir, &name, capture.cameras.len(), image)?;
+                }
             }
         }
     }
 
     Ok(())
 }
+
+fn render_frame(
+    parameters: &SimulationParameters,
+    scene: &Scene,
+    camera: &Camera,
+    image: &ImageSettings,
+    name: &str,
+    fingerprint: u64,
+    selection: &TileSelection,
+) -> Result<(), Box<dyn Error>> {
+    let options = RenderOptions::default().with_progress(TerminalProgress::new());
+
+    if *selection != TileSelection::All {
+        let (region_min, region_max) = image.region();
+        let tiles = Tile::create_tiles_within(region_min, region_max, image.tile_size);
+        let tiles_total = tiles.len();
+
+        let (rendered_tiles, status) = render_tiles(
+            image,
+            &parameters.lighting_settings,
+            &parameters.probe_settings,
+            camera,
+            &parameters.resources,
+            scene,
+            selection.select(tiles),
+            &options,
+        );
+
+        let shard_path = parameters
+            .output_dir
+            .join(format!("{name}-{}.shard", shard_label(selection, tiles_total)));
+
+        Shard {
+            name: name.to_owned(),
+            fingerprint,
+            background: image.background,
+            output_origin: image.output_origin(),
+            output_resolution: image.output_resolution(),
+            tiles_total,
+            status,
+            tiles: rendered_tiles,
+        }
+        .save(&shard_path)?;
+
+        println!("      Wrote {}", shard_path.display());
+        return Ok(());
+    }
+
+    let visual_image_path = parameters.output_dir.join(format!("{name}.png"));
+    let temporal_image_path = parameters.output_dir.join(format!("{name}-temporal.png"));
+
+    if image.stream {
+        if image.crop.is_some_and(|crop| crop.output == CropOutput::Paste) {
+            return Err(format!("Image '{name}' cannot both stream and paste its crop window").into());
+        }
+
+        render_image_streaming(
+            image,
+            &parameters.lighting_settings,
+            &parameters.probe_settings,
+            camera,
+            &parameters.resources,
+            scene,
+            &options,
+            &visual_image_path,
+            &temporal_image_path,
+        )?;
+        return Ok(());
+    }
+
+    let output = render_image(
+        image,
+        &parameters.lighting_settings,
+        &parameters.probe_settings,
+        camera,
+        &parameters.resources,
+        scene,
+        &options,
+    );
+
+    // output.visual.tone_map();
+    save_image(image, &output.visual, &visual_image_path)?;
+    save_image(image, &output.temporal, &temporal_image_path)?;
+
+    Ok(())
+}
+
+// Frames are read back from disk so that streamed and pasted frames are assembled the same way as the rest.
+fn assemble_sequence(
+    sequence: &Sequence,
+    output_dir: &Path,
+    name: &str,
+    frame_count: usize,
+    image: &ImageSettings,
+) -> Result<(), Box<dyn Error>> {
+    let frames = (0..frame_count)
+        .map(|frame| RgbaImage::load(output_dir.join(format!("{}.png", Sequence::frame_name(name, frame)))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if let Some(animation) = sequence.animation {
+        let path = output_dir.join(format!("{}.png", Sequence::animation_name(name)));
+        RgbaImage::save_animated(&frames, animation.fps, animation.plays, &path)?;
+        println!("      Wrote {}", path.display());
+    }
+
+    if let Some(contact_sheet) = sequence.contact_sheet {
+        let path = output_dir.join(format!("{}.png", Sequence::contact_sheet_name(name)));
+        RgbaImage::contact_sheet(&frames, contact_sheet.columns, image.background).save(&path)?;
+        println!("      Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn merge(output_dir: &Path, shard_paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    let mut shards = BTreeMap::<String, Vec<Shard>>::new();
+
+    for path in shard_paths {
+        let shard = Shard::load(path).map_err(|err| format!("Failed to load shard '{}': {err}", path.display()))?;
+        shards.entry(shard.name.clone()).or_default().push(shard);
+    }
+
+    create_dir_all(output_dir)?;
+
+    for (name, shards) in shards {
+        println!("> Merging {} shard(s) of {name}", shards.len());
+
+        let output = Shard::merge(shards)?;
+
+        output.visual.save(output_dir.join(format!("{name}.png")))?;
+        output.temporal.save(output_dir.join(format!("{name}-temporal.png")))?;
+    }
+
+    Ok(())
+}
+
+fn serve(address: &str) -> Result<(), Box<dyn Error>> {
+    let server = Server::bind(address)?;
+    println!("> Listening on http://{}", server.local_addr()?);
+
+    server.run()?;
+    Ok(())
+}
+
+fn shard_label(selection: &TileSelection, tiles_total: usize) -> String {
+    if let TileSelection::Shard { index, count } = selection {
+        return format!("shard-{}-of-{count}", index + 1);
+    }
+
+    let range = selection.range(tiles_total);
+    format!("tiles-{}-{}", range.start, range.end)
+}
+
+fn save_image(settings: &ImageSettings, image: &RgbaImage, path: &Path) -> Result<(), Box<dyn Error>> {
+    let Some(crop) = settings.crop.filter(|crop| crop.output == CropOutput::Paste) else {
+        image.save(path)?;
+        return Ok(());
+    };
+
+    let mut existing = RgbaImage::load(path)?;
+
+    if existing.size() != settings.resolution {
+        return Err(format!(
+            "Cannot paste crop into '{}': expected a {:?} image, found {:?}",
+            path.display(),
+            settings.resolution,
+            existing.size()
+        )
+        .into());
+    }
+
+    existing.paste(image, crop.window.pixel_bounds(settings.resolution).0);
+    existing.save(path)?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::f32::consts::TAU;
+
+use nalgebra::Point2;
+use rand::{Rng, RngExt};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aperture {
+    Circular,
+    Polygonal { blades: usize, rotation: f32 },
+}
+
+impl Aperture {
+    #[must_use]
+    #[inline]
+    pub fn polygonal(blades: usize, rotation: f32) -> Self {
+        assert!(blades >= 3, "Polygonal apertures must have at least three blades.");
+        Self::Polygonal { blades, rotation }
+    }
+
+    #[must_use]
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Point2<f32> {
+        match *self {
+            Self::Circular => {
+                let radius = rng.random::<f32>().sqrt();
+                let theta = rng.random::<f32>() * TAU;
+                Point2::new(radius * theta.cos(), radius * theta.sin())
+            }
+            Self::Polygonal { blades, rotation } => {
+                let segment = TAU / blades as f32;
+                let blade = rng.random_range(0..blades) as f32;
+
+                let (sin_a, cos_a) = blade.mul_add(segment, rotation).sin_cos();
+                let (sin_b, cos_b) = (blade + 1.0).mul_add(segment, rotation).sin_cos();
+
+                let mut u = rng.random::<f32>();
+                let mut v = rng.random::<f32>();
+                if u + v > 1.0 {
+                    u = 1.0 - u;
+                    v = 1.0 - v;
+                }
+
+                Point2::new(u.mul_add(cos_a, v * cos_b), u.mul_add(sin_a, v * sin_b))
+            }
+        }
+    }
+}
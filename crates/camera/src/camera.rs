@@ -1,18 +1,58 @@
-use antler_geometry::Ray;
-use nalgebra::Point2;
+use nalgebra::{Isometry3, Point2};
+use rand::Rng;
 
-use crate::{observer::Observer, orthographic::Orthographic, perspective::Perspective};
+use crate::{
+    camera_ray::CameraRay, cubemap::Cubemap, equirectangular::Equirectangular, fisheye::Fisheye,
+    motion_blur::MotionBlur, observer::Observer, orthographic::Orthographic, perspective::Perspective, stereo::Stereo,
+};
 
 pub enum Camera {
     Orthographic(Orthographic),
     Perspective(Perspective),
+    Equirectangular(Equirectangular),
+    Fisheye(Fisheye),
+    Cubemap(Cubemap),
+    Stereo(Stereo),
+    MotionBlur(MotionBlur),
+}
+
+impl Camera {
+    #[must_use]
+    #[inline]
+    pub fn transform(&self) -> &Isometry3<f32> {
+        match self {
+            Self::Orthographic(camera) => &camera.transform,
+            Self::Perspective(camera) => &camera.transform,
+            Self::Equirectangular(camera) => &camera.transform,
+            Self::Fisheye(camera) => &camera.transform,
+            Self::Cubemap(camera) => &camera.transform,
+            Self::Stereo(camera) => camera.camera.transform(),
+            Self::MotionBlur(camera) => camera.camera.transform(),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn exposure(&self) -> f32 {
+        match self {
+            Self::Perspective(camera) => camera.exposure,
+            Self::Stereo(camera) => camera.camera.exposure(),
+            Self::MotionBlur(camera) => camera.camera.exposure(),
+            Self::Orthographic(_) | Self::Equirectangular(_) | Self::Fisheye(_) | Self::Cubemap(_) => 1.0,
+        }
+    }
 }
 
 impl Observer for Camera {
-    fn emit(&self, resolution: [usize; 2], uv: Point2<f32>) -> Ray {
+    fn emit<R: Rng>(&self, rng: &mut R, resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
         match self {
-            Self::Orthographic(camera) => camera.emit(resolution, uv),
-            Self::Perspective(camera) => camera.emit(resolution, uv),
+            Self::Orthographic(camera) => camera.emit(rng, resolution, uv),
+            Self::Perspective(camera) => camera.emit(rng, resolution, uv),
+            Self::Equirectangular(camera) => camera.emit(rng, resolution, uv),
+            Self::Fisheye(camera) => camera.emit(rng, resolution, uv),
+            Self::Cubemap(camera) => camera.emit(rng, resolution, uv),
+            Self::Stereo(camera) => camera.emit(rng, resolution, uv),
+            Self::MotionBlur(camera) => camera.emit(rng, resolution, uv),
         }
     }
 }
@@ -30,3 +70,38 @@ impl From<Perspective> for Camera {
         Self::Perspective(val)
     }
 }
+
+impl From<Equirectangular> for Camera {
+    #[inline]
+    fn from(val: Equirectangular) -> Self {
+        Self::Equirectangular(val)
+    }
+}
+
+impl From<Fisheye> for Camera {
+    #[inline]
+    fn from(val: Fisheye) -> Self {
+        Self::Fisheye(val)
+    }
+}
+
+impl From<Cubemap> for Camera {
+    #[inline]
+    fn from(val: Cubemap) -> Self {
+        Self::Cubemap(val)
+    }
+}
+
+impl From<Stereo> for Camera {
+    #[inline]
+    fn from(val: Stereo) -> Self {
+        Self::Stereo(val)
+    }
+}
+
+impl From<MotionBlur> for Camera {
+    #[inline]
+    fn from(val: MotionBlur) -> Self {
+        Self::MotionBlur(val)
+    }
+}
@@ -0,0 +1,24 @@
+use antler_colour::Rgb;
+use antler_geometry::Ray;
+
+pub struct CameraRay {
+    pub ray: Ray,
+    pub weight: Rgb,
+}
+
+impl CameraRay {
+    #[must_use]
+    #[inline]
+    pub const fn new(ray: Ray) -> Self {
+        Self {
+            ray,
+            weight: Rgb::WHITE,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn weighted(ray: Ray, weight: Rgb) -> Self {
+        Self { ray, weight }
+    }
+}
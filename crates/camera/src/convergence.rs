@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Convergence {
+    ToeIn { distance: f32 },
+    OffAxis { distance: f32 },
+    Omnidirectional,
+}
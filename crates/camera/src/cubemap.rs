@@ -0,0 +1,54 @@
+use antler_geometry::Ray;
+use nalgebra::{Isometry3, Point2, Point3, Unit, Vector3};
+use rand::Rng;
+
+use crate::{camera_ray::CameraRay, cubemap_layout::CubemapLayout, observer::Observer};
+
+// Forward, right and up vectors of each face in +X, -X, +Y, -Y, +Z, -Z order, chosen so faces meet seamlessly in a cross.
+const FACES: [[[f32; 3]; 3]; 6] = [
+    [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]],
+    [[-1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [0.0, 1.0, 0.0]],
+    [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+    [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, -1.0]],
+    [[0.0, 0.0, 1.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    [[0.0, 0.0, -1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+];
+
+pub struct Cubemap {
+    pub transform: Isometry3<f32>,
+    pub layout: CubemapLayout,
+}
+
+impl Cubemap {
+    #[must_use]
+    pub fn new(position: Point3<f32>, look_at: Point3<f32>, up: Unit<Vector3<f32>>, layout: CubemapLayout) -> Self {
+        Self {
+            transform: Isometry3::look_at_rh(&position, &look_at, &up).inverse(),
+            layout,
+        }
+    }
+}
+
+impl Observer for Cubemap {
+    fn emit<R: Rng>(&self, _rng: &mut R, _resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
+        let [columns, rows] = self.layout.grid();
+
+        let u = uv.x * columns as f32;
+        let v = uv.y * rows as f32;
+        let cell = [
+            (u.floor() as usize).min(columns - 1),
+            (v.floor() as usize).min(rows - 1),
+        ];
+
+        let [forward, right, up] = FACES[self.layout.face(cell)?].map(Vector3::from);
+
+        let s = 2.0f32.mul_add(u - cell[0] as f32, -1.0);
+        let t = 2.0f32.mul_add(v - cell[1] as f32, -1.0);
+        let local_direction = forward + right * s - up * t;
+
+        let origin = self.transform.transform_point(&Point3::origin());
+        let direction = Unit::new_normalize(self.transform.transform_vector(&local_direction));
+
+        Some(CameraRay::new(Ray::new(origin, direction)))
+    }
+}
@@ -0,0 +1,38 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubemapLayout {
+    Cross,
+    Strip,
+}
+
+impl CubemapLayout {
+    #[must_use]
+    #[inline]
+    pub const fn grid(self) -> [usize; 2] {
+        match self {
+            Self::Cross => [4, 3],
+            Self::Strip => [6, 1],
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn face(self, cell: [usize; 2]) -> Option<usize> {
+        match self {
+            // A horizontal cross with the front face at its centre:
+            //       top
+            // left front right back
+            //      bottom
+            Self::Cross => match cell {
+                [1, 0] => Some(2),
+                [0, 1] => Some(1),
+                [1, 1] => Some(5),
+                [2, 1] => Some(0),
+                [3, 1] => Some(4),
+                [1, 2] => Some(3),
+                _ => None,
+            },
+            // Faces in +X, -X, +Y, -Y, +Z, -Z order.
+            Self::Strip => Some(cell[0]),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use nalgebra::Point2;
+
+const UNDISTORT_ITERATIONS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distortion {
+    pub radial: [f32; 3],
+    pub tangential: [f32; 2],
+}
+
+impl Distortion {
+    #[must_use]
+    #[inline]
+    pub const fn new(radial: [f32; 3], tangential: [f32; 2]) -> Self {
+        Self { radial, tangential }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn distort(&self, point: Point2<f32>) -> Point2<f32> {
+        let [k1, k2, k3] = self.radial;
+        let [p1, p2] = self.tangential;
+        let (x, y) = (point.x, point.y);
+
+        let r2 = x.mul_add(x, y * y);
+        let radial = r2.mul_add(r2.mul_add(r2.mul_add(k3, k2), k1), 1.0);
+
+        Point2::new(
+            x.mul_add(radial, (2.0 * p1 * x).mul_add(y, p2 * (2.0 * x).mul_add(x, r2))),
+            y.mul_add(radial, p1.mul_add((2.0 * y).mul_add(y, r2), 2.0 * p2 * x * y)),
+        )
+    }
+
+    // Brown–Conrady has no closed-form inverse, so refine by fixed-point iteration.
+    #[must_use]
+    pub fn undistort(&self, point: Point2<f32>) -> Point2<f32> {
+        let mut undistorted = point;
+
+        for _ in 0..UNDISTORT_ITERATIONS {
+            undistorted += point - self.distort(undistorted);
+        }
+
+        undistorted
+    }
+}
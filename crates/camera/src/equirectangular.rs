@@ -0,0 +1,38 @@
+use std::f32::consts::{PI, TAU};
+
+use antler_geometry::Ray;
+use nalgebra::{Isometry3, Point2, Point3, Unit, Vector3};
+use rand::Rng;
+
+use crate::{camera_ray::CameraRay, observer::Observer};
+
+pub struct Equirectangular {
+    pub transform: Isometry3<f32>,
+}
+
+impl Equirectangular {
+    #[must_use]
+    pub fn new(position: Point3<f32>, look_at: Point3<f32>, up: Unit<Vector3<f32>>) -> Self {
+        Self {
+            transform: Isometry3::look_at_rh(&position, &look_at, &up).inverse(),
+        }
+    }
+}
+
+impl Observer for Equirectangular {
+    fn emit<R: Rng>(&self, _rng: &mut R, _resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
+        let longitude = (uv.x - 0.5) * TAU;
+        let latitude = (0.5 - uv.y) * PI;
+
+        let local_direction = Vector3::new(
+            latitude.cos() * longitude.sin(),
+            latitude.sin(),
+            -latitude.cos() * longitude.cos(),
+        );
+
+        let origin = self.transform.transform_point(&Point3::origin());
+        let direction = Unit::new_normalize(self.transform.transform_vector(&local_direction));
+
+        Some(CameraRay::new(Ray::new(origin, direction)))
+    }
+}
@@ -0,0 +1,37 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exposure {
+    pub f_stop: f32,
+    pub shutter: f32,
+    pub iso: f32,
+    pub compensation: f32,
+}
+
+impl Exposure {
+    #[must_use]
+    #[inline]
+    pub fn new(f_stop: f32, shutter: f32, iso: f32, compensation: f32) -> Self {
+        assert!(f_stop > 0.0, "Exposure f-stop must be positive.");
+        assert!(shutter > 0.0, "Exposure shutter time must be positive.");
+        assert!(iso > 0.0, "Exposure ISO must be positive.");
+
+        Self {
+            f_stop,
+            shutter,
+            iso,
+            compensation,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn ev100(&self) -> f32 {
+        (self.f_stop * self.f_stop / self.shutter * 100.0 / self.iso).log2()
+    }
+
+    // Saturation-based sensitivity: the radiance that just saturates the sensor maps to one.
+    #[must_use]
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        (1.2 * (self.ev100() - self.compensation).exp2()).recip()
+    }
+}
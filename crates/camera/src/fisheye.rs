@@ -0,0 +1,62 @@
+use antler_geometry::Ray;
+use nalgebra::{Isometry3, Point2, Point3, Unit, Vector3};
+use rand::Rng;
+
+use crate::{camera_ray::CameraRay, fisheye_projection::FisheyeProjection, observer::Observer};
+
+pub struct Fisheye {
+    pub transform: Isometry3<f32>,
+    pub fov: f32,
+    pub projection: FisheyeProjection,
+}
+
+impl Fisheye {
+    #[must_use]
+    pub fn new(
+        position: Point3<f32>,
+        look_at: Point3<f32>,
+        up: Unit<Vector3<f32>>,
+        fov: f32,
+        projection: FisheyeProjection,
+    ) -> Self {
+        assert!(
+            fov > 0.0 && fov <= std::f32::consts::TAU,
+            "Fisheye field of view must be within (0, 2π]."
+        );
+
+        Self {
+            transform: Isometry3::look_at_rh(&position, &look_at, &up).inverse(),
+            fov,
+            projection,
+        }
+    }
+}
+
+impl Observer for Fisheye {
+    fn emit<R: Rng>(&self, _rng: &mut R, resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
+        let aspect_ratio = resolution[0] as f32 / resolution[1] as f32;
+
+        // The image circle spans the shorter side of the frame.
+        let (scale_x, scale_y) = if aspect_ratio >= 1.0 {
+            (aspect_ratio, 1.0)
+        } else {
+            (1.0, aspect_ratio.recip())
+        };
+        let x = 2.0f32.mul_add(uv.x, -1.0) * scale_x;
+        let y = 2.0f32.mul_add(-uv.y, 1.0) * scale_y;
+
+        let radius = x.hypot(y);
+        if radius > 1.0 {
+            return None;
+        }
+
+        let theta = self.projection.polar_angle(radius, self.fov);
+        let phi = y.atan2(x);
+        let local_direction = Vector3::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), -theta.cos());
+
+        let origin = self.transform.transform_point(&Point3::origin());
+        let direction = Unit::new_normalize(self.transform.transform_vector(&local_direction));
+
+        Some(CameraRay::new(Ray::new(origin, direction)))
+    }
+}
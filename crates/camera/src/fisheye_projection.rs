@@ -0,0 +1,16 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FisheyeProjection {
+    Equidistant,
+    Equisolid,
+}
+
+impl FisheyeProjection {
+    #[must_use]
+    #[inline]
+    pub fn polar_angle(self, radius: f32, fov: f32) -> f32 {
+        match self {
+            Self::Equidistant => radius * fov * 0.5,
+            Self::Equisolid => 2.0 * (radius * (fov * 0.25).sin()).asin(),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use antler_geometry::Aabb;
+use nalgebra::{Point3, Unit, Vector3};
+
+pub struct Framing {
+    pub bounds: Aabb,
+    pub margin: f32,
+}
+
+impl Framing {
+    #[must_use]
+    pub fn new(bounds: Aabb, margin: f32) -> Self {
+        assert!(margin >= 0.0, "Framing margin must be non-negative.");
+
+        Self { bounds, margin }
+    }
+
+    // Moves the camera along its current view direction until the bounds fit within the frustum.
+    #[must_use]
+    pub fn perspective(
+        &self,
+        position: Point3<f32>,
+        look_at: Point3<f32>,
+        up: Unit<Vector3<f32>>,
+        vertical_fov: f32,
+        aspect_ratio: f32,
+    ) -> (Point3<f32>, Point3<f32>) {
+        let (forward, right, up) = basis(position, look_at, up);
+        let centre = self.bounds.centroid();
+
+        let tan_y = (vertical_fov * 0.5).tan() / (1.0 + self.margin);
+        let tan_x = tan_y * aspect_ratio;
+
+        // A corner at (x, y, z) relative to the centre is in frame once the camera is at least |x| / tan_x - z back.
+        let distance = self
+            .corners(centre)
+            .map(|offset| {
+                let depth = offset.dot(&forward);
+                (offset.dot(&right).abs() / tan_x).max(offset.dot(&up).abs() / tan_y) - depth
+            })
+            .into_iter()
+            .fold(0.0, f32::max);
+
+        (centre - forward * distance, centre)
+    }
+
+    // Fits the bounding sphere within a cone, for cameras whose field of view is radially symmetric.
+    #[must_use]
+    pub fn cone(&self, position: Point3<f32>, look_at: Point3<f32>, half_angle: f32) -> (Point3<f32>, Point3<f32>) {
+        let forward = Unit::new_normalize(look_at - position);
+        let centre = self.bounds.centroid();
+
+        let radius = self.radius() * (1.0 + self.margin);
+        let distance = radius / half_angle.min(std::f32::consts::FRAC_PI_2).sin();
+
+        (centre - forward.into_inner() * distance, centre)
+    }
+
+    // Re-centres the camera on the bounds and sizes its view so they fit at the image aspect ratio.
+    #[must_use]
+    pub fn orthographic(
+        &self,
+        position: Point3<f32>,
+        look_at: Point3<f32>,
+        up: Unit<Vector3<f32>>,
+        aspect_ratio: f32,
+    ) -> (Point3<f32>, Point3<f32>, [f32; 2]) {
+        let (forward, right, up) = basis(position, look_at, up);
+        let centre = self.bounds.centroid();
+
+        let (half_width, half_height) = self
+            .corners(centre)
+            .into_iter()
+            .fold((0.0f32, 0.0f32), |(width, height), offset| {
+                (width.max(offset.dot(&right).abs()), height.max(offset.dot(&up).abs()))
+            });
+
+        let half_height = half_height.max(half_width / aspect_ratio) * (1.0 + self.margin);
+        let half_width = half_height * aspect_ratio;
+
+        // Orthographic rays start on the image plane, so it must sit clear of the bounds.
+        let distance = self.radius() * 2.0;
+
+        (
+            centre - forward * distance,
+            centre,
+            [half_width * 2.0, half_height * 2.0],
+        )
+    }
+
+    fn radius(&self) -> f32 {
+        (self.bounds.max - self.bounds.min).norm() * 0.5
+    }
+
+    fn corners(&self, centre: Point3<f32>) -> [Vector3<f32>; 8] {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+
+        [
+            Point3::new(min.x, min.y, min.z),
+            Point3::new(max.x, min.y, min.z),
+            Point3::new(min.x, max.y, min.z),
+            Point3::new(max.x, max.y, min.z),
+            Point3::new(min.x, min.y, max.z),
+            Point3::new(max.x, min.y, max.z),
+            Point3::new(min.x, max.y, max.z),
+            Point3::new(max.x, max.y, max.z),
+        ]
+        .map(|corner| corner - centre)
+    }
+}
+
+fn basis(
+    position: Point3<f32>,
+    look_at: Point3<f32>,
+    up: Unit<Vector3<f32>>,
+) -> (Vector3<f32>, Vector3<f32>, Vector3<f32>) {
+    let forward = (look_at - position).normalize();
+    let right = forward.cross(&up).normalize();
+    let up = right.cross(&forward);
+
+    (forward, right, up)
+}
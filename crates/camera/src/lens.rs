@@ -0,0 +1,32 @@
+use nalgebra::Point2;
+use rand::Rng;
+
+use crate::aperture::Aperture;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lens {
+    pub aperture_radius: f32,
+    pub focus_distance: f32,
+    pub aperture: Aperture,
+}
+
+impl Lens {
+    #[must_use]
+    #[inline]
+    pub fn new(aperture_radius: f32, focus_distance: f32, aperture: Aperture) -> Self {
+        assert!(aperture_radius >= 0.0, "Lens aperture radius must be non-negative.");
+        assert!(focus_distance > 0.0, "Lens focus distance must be positive.");
+
+        Self {
+            aperture_radius,
+            focus_distance,
+            aperture,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Point2<f32> {
+        self.aperture.sample(rng) * self.aperture_radius
+    }
+}
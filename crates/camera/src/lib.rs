@@ -1,9 +1,41 @@
+mod aperture;
 mod camera;
+mod camera_ray;
+mod convergence;
+mod cubemap;
+mod cubemap_layout;
+mod distortion;
+mod equirectangular;
+mod exposure;
+mod fisheye;
+mod fisheye_projection;
+mod framing;
+mod lens;
+mod motion_blur;
 mod observer;
+mod optics;
 mod orthographic;
 mod perspective;
+mod stereo;
+mod stereo_layout;
 
+pub use aperture::Aperture;
 pub use camera::Camera;
+pub use camera_ray::CameraRay;
+pub use convergence::Convergence;
+pub use cubemap::Cubemap;
+pub use cubemap_layout::CubemapLayout;
+pub use distortion::Distortion;
+pub use equirectangular::Equirectangular;
+pub use exposure::Exposure;
+pub use fisheye::Fisheye;
+pub use fisheye_projection::FisheyeProjection;
+pub use framing::Framing;
+pub use lens::Lens;
+pub use motion_blur::MotionBlur;
 pub use observer::Observer;
+pub use optics::Optics;
 pub use orthographic::Orthographic;
 pub use perspective::Perspective;
+pub use stereo::Stereo;
+pub use stereo_layout::StereoLayout;
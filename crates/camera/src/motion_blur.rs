@@ -0,0 +1,45 @@
+use antler_geometry::Ray;
+use nalgebra::{Isometry3, Point2};
+use rand::{Rng, RngExt};
+
+use crate::{camera::Camera, camera_ray::CameraRay, observer::Observer};
+
+pub struct MotionBlur {
+    pub camera: Box<Camera>,
+    pub shutter: [f32; 2],
+    pub end_transform: Option<Isometry3<f32>>,
+}
+
+impl MotionBlur {
+    #[must_use]
+    pub fn new(camera: Camera, shutter: [f32; 2], end_transform: Option<Isometry3<f32>>) -> Self {
+        assert!(
+            0.0 <= shutter[0] && shutter[0] <= shutter[1] && shutter[1] <= 1.0,
+            "Shutter interval must satisfy 0 <= open <= close <= 1."
+        );
+
+        Self {
+            camera: Box::new(camera),
+            shutter,
+            end_transform,
+        }
+    }
+}
+
+impl Observer for MotionBlur {
+    fn emit<R: Rng>(&self, rng: &mut R, resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
+        let [open, close] = self.shutter;
+        let time = rng.random::<f32>().mul_add(close - open, open);
+
+        let CameraRay { ray, weight } = self.camera.emit(rng, resolution, uv)?;
+
+        // The wrapped camera emits from its start pose, so its rays are carried along to the pose at this time.
+        let ray = self.end_transform.map_or(ray, |end_transform| {
+            let start_transform = self.camera.transform();
+            let motion = start_transform.lerp_slerp(&end_transform, time) * start_transform.inverse();
+            Ray::new(motion * ray.origin, motion * ray.direction)
+        });
+
+        Some(CameraRay::weighted(ray.with_time(time), weight))
+    }
+}
@@ -1,6 +1,8 @@
-use antler_geometry::Ray;
 use nalgebra::Point2;
+use rand::Rng;
+
+use crate::camera_ray::CameraRay;
 
 pub trait Observer {
-    fn emit(&self, resolution: [usize; 2], uv: Point2<f32>) -> Ray;
+    fn emit<R: Rng>(&self, rng: &mut R, resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay>;
 }
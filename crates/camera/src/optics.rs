@@ -0,0 +1,38 @@
+use antler_colour::Rgb;
+use nalgebra::Point2;
+use rand::{Rng, RngExt};
+
+use crate::distortion::Distortion;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Optics {
+    pub distortion: Option<Distortion>,
+    pub vignetting: [f32; 3],
+    pub chromatic_aberration: f32,
+}
+
+impl Optics {
+    // Map a point on the distorted image plane to the undistorted plane, weighting for vignetting and fringing.
+    #[must_use]
+    pub fn apply<R: Rng>(&self, rng: &mut R, point: Point2<f32>) -> (Point2<f32>, Rgb) {
+        let [v1, v2, v3] = self.vignetting;
+        let r2 = point.coords.norm_squared();
+        let falloff = r2.mul_add(r2.mul_add(r2.mul_add(v3, v2), v1), 1.0).max(0.0);
+
+        let (point, weight) = if self.chromatic_aberration == 0.0 {
+            (point, Rgb::WHITE * falloff)
+        } else {
+            // Each channel is magnified differently, so trace one channel per sample and compensate its weight.
+            let channel = rng.random_range(0..3);
+            let magnification = self.chromatic_aberration.mul_add(1.0 - channel as f32, 1.0);
+
+            let mut weight = [0.0; 3];
+            weight[channel] = 3.0 * falloff;
+
+            (point / magnification, Rgb::new(weight[0], weight[1], weight[2]))
+        };
+
+        let point = self.distortion.map_or(point, |distortion| distortion.undistort(point));
+        (point, weight)
+    }
+}
@@ -1,7 +1,8 @@
 use antler_geometry::Ray;
 use nalgebra::{Isometry3, Point2, Point3, Unit, Vector3};
+use rand::Rng;
 
-use crate::observer::Observer;
+use crate::{camera_ray::CameraRay, observer::Observer};
 
 pub struct Orthographic {
     pub transform: Isometry3<f32>,
@@ -19,7 +20,7 @@ impl Orthographic {
 }
 
 impl Observer for Orthographic {
-    fn emit(&self, _resolution: [usize; 2], uv: Point2<f32>) -> Ray {
+    fn emit<R: Rng>(&self, _rng: &mut R, _resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
         let x = 2.0f32.mul_add(uv.x, -1.0) * self.size[0] * 0.5;
         let y = 2.0f32.mul_add(-uv.y, 1.0) * self.size[1] * 0.5;
 
@@ -29,6 +30,6 @@ impl Observer for Orthographic {
         let origin = self.transform.transform_point(&local_origin);
         let direction = Unit::new_normalize(self.transform.transform_vector(&local_direction.into_inner()));
 
-        Ray { origin, direction }
+        Some(CameraRay::new(Ray::new(origin, direction)))
     }
 }
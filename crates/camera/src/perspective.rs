@@ -1,11 +1,16 @@
+use antler_colour::Rgb;
 use antler_geometry::Ray;
 use nalgebra::{Isometry3, Point2, Point3, Unit, Vector3};
+use rand::Rng;
 
-use crate::observer::Observer;
+use crate::{camera_ray::CameraRay, lens::Lens, observer::Observer, optics::Optics};
 
 pub struct Perspective {
     pub transform: Isometry3<f32>,
     pub vertical_fov: f32,
+    pub lens: Option<Lens>,
+    pub exposure: f32,
+    pub optics: Option<Optics>,
 }
 
 impl Perspective {
@@ -14,24 +19,62 @@ impl Perspective {
         Self {
             transform: Isometry3::look_at_rh(&position, &look_at, &up).inverse(),
             vertical_fov,
+            lens: None,
+            exposure: 1.0,
+            optics: None,
         }
     }
+
+    #[must_use]
+    #[inline]
+    pub const fn with_lens(mut self, lens: Lens) -> Self {
+        self.lens = Some(lens);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn with_exposure(mut self, exposure: f32) -> Self {
+        self.exposure = exposure;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn with_optics(mut self, optics: Optics) -> Self {
+        self.optics = Some(optics);
+        self
+    }
 }
 
 impl Observer for Perspective {
-    fn emit(&self, resolution: [usize; 2], uv: Point2<f32>) -> Ray {
+    fn emit<R: Rng>(&self, rng: &mut R, resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
         let aspect_ratio = resolution[0] as f32 / resolution[1] as f32;
         let tan_half_fov = (self.vertical_fov * 0.5).tan();
 
         let x = 2.0f32.mul_add(uv.x, -1.0) * aspect_ratio * tan_half_fov;
         let y = 2.0f32.mul_add(-uv.y, 1.0) * tan_half_fov;
 
-        let local_origin = Point3::new(0.0, 0.0, 0.0);
-        let local_direction = Unit::new_normalize(Vector3::new(x, y, -1.0));
+        let point = Point2::new(x, y);
+        let (point, weight) = self
+            .optics
+            .map_or((point, Rgb::WHITE), |optics| optics.apply(rng, point));
+        let pinhole_direction = Vector3::new(point.x, point.y, -1.0);
+
+        let (local_origin, local_direction) = match self.lens {
+            Some(lens) if lens.aperture_radius > 0.0 => {
+                // Every ray through the lens converges on the pinhole ray's point on the focal plane.
+                let offset = lens.sample(rng);
+                let lens_point = Point3::new(offset.x, offset.y, 0.0);
+                let focus_point = Point3::from(pinhole_direction * lens.focus_distance);
+                (lens_point, Unit::new_normalize(focus_point - lens_point))
+            }
+            _ => (Point3::origin(), Unit::new_normalize(pinhole_direction)),
+        };
 
         let origin = self.transform.transform_point(&local_origin);
         let direction = Unit::new_normalize(self.transform.transform_vector(&local_direction.into_inner()));
 
-        Ray::new(origin, direction)
+        Some(CameraRay::weighted(Ray::new(origin, direction), weight))
     }
 }
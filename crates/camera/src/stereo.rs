@@ -0,0 +1,93 @@
+use antler_geometry::Ray;
+use nalgebra::{Point2, Rotation3, Unit, Vector3};
+use rand::Rng;
+
+use crate::{
+    camera::Camera, camera_ray::CameraRay, convergence::Convergence, observer::Observer, stereo_layout::StereoLayout,
+};
+
+pub struct Stereo {
+    pub camera: Box<Camera>,
+    pub interpupillary_distance: f32,
+    pub convergence: Convergence,
+    pub layout: StereoLayout,
+}
+
+impl Stereo {
+    #[must_use]
+    pub fn new(camera: Camera, interpupillary_distance: f32, convergence: Convergence, layout: StereoLayout) -> Self {
+        assert!(
+            !matches!(camera, Camera::Stereo(_)),
+            "Stereo rigs cannot wrap another stereo rig."
+        );
+        assert!(
+            interpupillary_distance >= 0.0,
+            "Interpupillary distance must be non-negative."
+        );
+        if let Convergence::ToeIn { distance } | Convergence::OffAxis { distance } = convergence {
+            assert!(distance > 0.0, "Convergence distance must be positive.");
+        }
+
+        Self {
+            camera: Box::new(camera),
+            interpupillary_distance,
+            convergence,
+            layout,
+        }
+    }
+}
+
+impl Observer for Stereo {
+    fn emit<R: Rng>(&self, rng: &mut R, resolution: [usize; 2], uv: Point2<f32>) -> Option<CameraRay> {
+        // Split the frame into per-eye halves, with the left eye on the left or top.
+        let (side, eye_resolution, eye_uv) = match self.layout {
+            StereoLayout::SideBySide => {
+                let (side, u) = if uv.x < 0.5 { (-1.0, uv.x) } else { (1.0, uv.x - 0.5) };
+                (side, [resolution[0] / 2, resolution[1]], Point2::new(u * 2.0, uv.y))
+            }
+            StereoLayout::TopBottom => {
+                let (side, v) = if uv.y < 0.5 { (-1.0, uv.y) } else { (1.0, uv.y - 0.5) };
+                (side, [resolution[0], resolution[1] / 2], Point2::new(uv.x, v * 2.0))
+            }
+        };
+
+        let CameraRay { ray, weight } = self.camera.emit(rng, eye_resolution.map(|n| n.max(1)), eye_uv)?;
+
+        let transform = self.camera.transform();
+        let local_origin = transform.inverse_transform_point(&ray.origin);
+        let local_direction = transform.inverse_transform_vector(&ray.direction);
+        let half_ipd = side * self.interpupillary_distance * 0.5;
+
+        let (eye_offset, eye_direction) = match self.convergence {
+            Convergence::ToeIn { distance } => {
+                let angle = half_ipd.atan2(distance);
+                let toe_in = Rotation3::from_axis_angle(&Vector3::y_axis(), angle);
+                (Vector3::new(half_ipd, 0.0, 0.0), toe_in * local_direction)
+            }
+            Convergence::OffAxis { distance } => {
+                // Both eyes aim at the point where the centre ray crosses the zero-parallax plane z = -distance. Rays
+                // that never reach the plane stay parallel, as if converging at infinity.
+                let offset = Vector3::new(half_ipd, 0.0, 0.0);
+                let direction = if local_direction.z < 0.0 {
+                    local_direction * (distance / -local_direction.z) - offset
+                } else {
+                    local_direction
+                };
+                (offset, direction)
+            }
+            Convergence::Omnidirectional => {
+                // Each eye sits on the viewing circle, tangent to the ray's horizontal heading.
+                let heading = Vector3::new(local_direction.x, 0.0, local_direction.z);
+                let right = heading
+                    .try_normalize(f32::EPSILON)
+                    .map_or_else(Vector3::zeros, |heading| Vector3::new(-heading.z, 0.0, heading.x));
+                (right * half_ipd, local_direction)
+            }
+        };
+
+        let origin = transform.transform_point(&(local_origin + eye_offset));
+        let direction = Unit::new_normalize(transform.transform_vector(&eye_direction));
+
+        Some(CameraRay::weighted(Ray::new(origin, direction), weight))
+    }
+}
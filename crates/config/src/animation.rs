@@ -0,0 +1,264 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use antler_id::ObjectId;
+use antler_parameters::{SceneParameters, SimulationParameters};
+use antler_scene::Resources;
+
+use crate::{
+    animation_config::{CameraTracks, LightTracks, ObjectTracks},
+    capture_config::CaptureConfig,
+    errors::ConfigError,
+    light_config::LightConfig,
+    scene_config::SceneConfig,
+    shader_config::ShaderConfig,
+    timeline_config::TimelineConfig,
+};
+
+pub struct Animation {
+    timeline: Option<TimelineConfig>,
+    scenes: BTreeMap<String, SceneAnimation>,
+}
+
+pub struct SceneAnimation {
+    object_names: BTreeMap<String, ObjectId>,
+    objects: Vec<AnimatedObject>,
+    lights: Vec<AnimatedLight>,
+    captures: Vec<AnimatedCapture>,
+    motion_blur: bool,
+}
+
+struct AnimatedObject {
+    name: String,
+    object_id: ObjectId,
+    tracks: ObjectTracks,
+    shader: ShaderConfig,
+}
+
+struct AnimatedLight {
+    index: usize,
+    tracks: LightTracks,
+    light: LightConfig,
+}
+
+// Captures are rebuilt every frame if their camera is animated or framed on bounds that may have moved.
+struct AnimatedCapture {
+    name: String,
+    tracks: Option<CameraTracks>,
+    capture: CaptureConfig,
+}
+
+impl Animation {
+    pub fn new(
+        timeline: Option<TimelineConfig>,
+        scenes: BTreeMap<String, SceneAnimation>,
+    ) -> Result<Self, ConfigError> {
+        if let Some(timeline) = timeline
+            && (timeline.start > timeline.end || timeline.fps <= 0.0)
+        {
+            return Err(ConfigError::Animation(format!(
+                "Timeline must have start <= end and a positive frame rate, found {}..={} at {} fps",
+                timeline.start, timeline.end, timeline.fps
+            )));
+        }
+
+        if timeline.is_none() && scenes.values().any(SceneAnimation::has_tracks) {
+            return Err(ConfigError::Animation(
+                "Keyframe tracks need a timeline in the manifest".to_owned(),
+            ));
+        }
+
+        Ok(Self { timeline, scenes })
+    }
+
+    #[must_use]
+    pub fn frames(&self) -> Option<RangeInclusive<usize>> {
+        self.timeline.map(|timeline| timeline.start..=timeline.end)
+    }
+
+    pub fn apply(&self, frame: usize, parameters: &mut SimulationParameters) -> Result<(), ConfigError> {
+        let Some(timeline) = self.timeline else {
+            return Ok(());
+        };
+        let time = timeline.time(frame);
+
+        for (scene_name, animation) in &self.scenes {
+            let scene = parameters
+                .scenes
+                .get_mut(scene_name)
+                .expect("every animated scene has been built");
+
+            animation.apply(time, timeline.frame_duration(), scene, &mut parameters.resources)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SceneAnimation {
+    pub fn new(config: &SceneConfig, object_names: BTreeMap<String, ObjectId>) -> Result<Self, ConfigError> {
+        let animation = &config.animation;
+
+        let objects = animation
+            .objects
+            .iter()
+            .map(|(name, tracks)| {
+                let object_id = *object_names
+                    .get(name)
+                    .ok_or_else(|| ConfigError::Animation(format!("Animation refers to unknown object '{name}'")))?;
+
+                Ok(AnimatedObject {
+                    name: name.clone(),
+                    object_id,
+                    tracks: tracks.clone(),
+                    shader: config.objects[object_id.index()].shader.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, ConfigError>>()?;
+
+        let lights = animation
+            .lights
+            .iter()
+            .map(|(&index, tracks)| {
+                let light = config.lights.get(index).ok_or_else(|| {
+                    ConfigError::Animation(format!(
+                        "Animation refers to light {index}, but the scene has {} light(s)",
+                        config.lights.len()
+                    ))
+                })?;
+
+                Ok(AnimatedLight {
+                    index,
+                    tracks: tracks.clone(),
+                    light: light.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, ConfigError>>()?;
+
+        if let Some(name) = animation
+            .captures
+            .keys()
+            .find(|name| !config.captures.contains_key(*name))
+        {
+            return Err(ConfigError::Animation(format!(
+                "Animation refers to unknown capture '{name}'"
+            )));
+        }
+
+        let captures = config
+            .captures
+            .iter()
+            .filter_map(|(name, capture)| {
+                let tracks = animation.captures.get(name).cloned();
+
+                (tracks.is_some() || capture.framing.is_some()).then(|| AnimatedCapture {
+                    name: name.clone(),
+                    tracks,
+                    capture: capture.clone(),
+                })
+            })
+            .collect();
+
+        let scene_animation = Self {
+            object_names,
+            objects,
+            lights,
+            captures,
+            motion_blur: config.captures.values().any(|capture| capture.shutter.is_some()),
+        };
+        scene_animation.validate()?;
+
+        Ok(scene_animation)
+    }
+
+    fn has_tracks(&self) -> bool {
+        !self.objects.is_empty()
+            || !self.lights.is_empty()
+            || self.captures.iter().any(|capture| capture.tracks.is_some())
+    }
+
+    // Applying every track once up front reports misapplied tracks before any rendering starts.
+    fn validate(&self) -> Result<(), ConfigError> {
+        for object in &self.objects {
+            if let Some(track) = &object.tracks.transform {
+                track.validate(&format!("{}.transform", object.name))?;
+            }
+            object
+                .tracks
+                .apply_shader(&object.name, &mut object.shader.clone(), 0.0)?;
+        }
+
+        for light in &self.lights {
+            light.tracks.apply(light.index, &mut light.light.clone(), 0.0)?;
+        }
+
+        for capture in &self.captures {
+            if let Some(tracks) = &capture.tracks {
+                tracks.apply(&capture.name, &mut capture.capture.camera.clone(), 0.0)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Shutter intervals span one frame, so motion blur ends where the next frame starts.
+    fn apply(
+        &self,
+        time: f32,
+        duration: f32,
+        parameters: &mut SceneParameters,
+        resources: &mut Resources,
+    ) -> Result<(), ConfigError> {
+        let scene = &mut parameters.scene;
+        let mut moved = false;
+
+        for object in &self.objects {
+            if let Some(track) = &object.tracks.transform {
+                let end_transform = self.motion_blur.then(|| track.sample(time + duration).into());
+
+                scene.set_object_transform(object.object_id, track.sample(time).into(), end_transform);
+                moved = true;
+            }
+
+            if object.tracks.animates_shader() {
+                let mut shader = object.shader.clone();
+                object.tracks.apply_shader(&object.name, &mut shader, time)?;
+
+                let shader_id = scene.get_object(object.object_id).shader_id;
+                resources.set_shader(shader_id, shader.build()?);
+            }
+        }
+
+        for light in &self.lights {
+            let mut config = light.light.clone();
+            light.tracks.apply(light.index, &mut config, time)?;
+            scene.set_light(ObjectId::new(light.index), config.build());
+        }
+
+        if moved {
+            scene.build(resources);
+        }
+
+        for capture in &self.captures {
+            let mut config = capture.capture.clone();
+            if let Some(tracks) = &capture.tracks {
+                if let Some(shutter) = &mut config.shutter {
+                    let mut end_camera = config.camera.clone();
+                    tracks.apply(&capture.name, &mut end_camera, time + duration)?;
+                    let (position, look_at) = end_camera.view();
+                    (shutter.position, shutter.look_at) = (Some(position), Some(look_at));
+                }
+
+                tracks.apply(&capture.name, &mut config.camera, time)?;
+            }
+
+            let cameras = config.cameras(scene, resources, &self.object_names)?;
+            parameters
+                .captures
+                .get_mut(&capture.name)
+                .expect("every animated capture has been built")
+                .cameras = cameras;
+        }
+
+        Ok(())
+    }
+}
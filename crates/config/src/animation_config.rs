@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+use antler_colour::Rgb;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera_config::CameraConfig, errors::ConfigError, light_config::LightConfig, shader_config::ShaderConfig,
+    track::Track, transform::Transform, vec3::Vec3,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationConfig {
+    #[serde(default)]
+    pub objects: BTreeMap<String, ObjectTracks>,
+    #[serde(default)]
+    pub lights: BTreeMap<usize, LightTracks>,
+    #[serde(default)]
+    pub captures: BTreeMap<String, CameraTracks>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectTracks {
+    #[serde(default)]
+    pub transform: Option<Track<Transform>>,
+    #[serde(default)]
+    pub colour: Option<Track<Rgb>>,
+    #[serde(default)]
+    pub secondary_colour: Option<Track<Rgb>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LightTracks {
+    #[serde(default)]
+    pub position: Option<Track<Vec3>>,
+    #[serde(default)]
+    pub direction: Option<Track<Vec3>>,
+    #[serde(default)]
+    pub colour: Option<Track<Rgb>>,
+    #[serde(default)]
+    pub intensity: Option<Track<f32>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CameraTracks {
+    #[serde(default)]
+    pub position: Option<Track<Vec3>>,
+    #[serde(default)]
+    pub look_at: Option<Track<Vec3>>,
+    #[serde(default)]
+    pub fov: Option<Track<f32>>,
+}
+
+impl ObjectTracks {
+    pub const fn animates_shader(&self) -> bool {
+        self.colour.is_some() || self.secondary_colour.is_some()
+    }
+
+    pub fn apply_shader(&self, name: &str, shader: &mut ShaderConfig, time: f32) -> Result<(), ConfigError> {
+        if let Some(track) = &self.colour {
+            track.validate(&format!("{name}.colour"))?;
+            if !shader.set_colour(track.sample(time)) {
+                return Err(unanimatable(&format!("Shader of object '{name}'"), "colour"));
+            }
+        }
+
+        if let Some(track) = &self.secondary_colour {
+            track.validate(&format!("{name}.secondary_colour"))?;
+            if !shader.set_secondary_colour(track.sample(time)) {
+                return Err(unanimatable(&format!("Shader of object '{name}'"), "secondary colour"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl LightTracks {
+    pub fn apply(&self, index: usize, light: &mut LightConfig, time: f32) -> Result<(), ConfigError> {
+        let name = format!("Light {index}");
+
+        if let Some(track) = &self.position {
+            track.validate(&format!("{name}.position"))?;
+            if !light.set_position(track.sample(time)) {
+                return Err(unanimatable(&name, "position"));
+            }
+        }
+
+        if let Some(track) = &self.direction {
+            track.validate(&format!("{name}.direction"))?;
+            if !light.set_direction(track.sample(time)) {
+                return Err(unanimatable(&name, "direction"));
+            }
+        }
+
+        if let Some(track) = &self.colour {
+            track.validate(&format!("{name}.colour"))?;
+            if !light.set_colour(track.sample(time)) {
+                return Err(unanimatable(&name, "colour"));
+            }
+        }
+
+        if let Some(track) = &self.intensity {
+            track.validate(&format!("{name}.intensity"))?;
+            if !light.set_intensity(track.sample(time)) {
+                return Err(unanimatable(&name, "intensity"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CameraTracks {
+    pub fn apply(&self, capture_name: &str, camera: &mut CameraConfig, time: f32) -> Result<(), ConfigError> {
+        let (mut position, mut look_at) = camera.view();
+
+        if let Some(track) = &self.position {
+            track.validate(&format!("{capture_name}.position"))?;
+            position = track.sample(time);
+        }
+
+        if let Some(track) = &self.look_at {
+            track.validate(&format!("{capture_name}.look_at"))?;
+            look_at = track.sample(time);
+        }
+
+        camera.aim(position, look_at);
+
+        if let Some(track) = &self.fov {
+            track.validate(&format!("{capture_name}.fov"))?;
+            if !camera.set_fov(track.sample(time)) {
+                return Err(unanimatable(
+                    &format!("Camera of capture '{capture_name}'"),
+                    "field of view",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn unanimatable(subject: &str, property: &str) -> ConfigError {
+    ConfigError::Animation(format!("{subject} has no {property} to animate"))
+}
@@ -1,9 +1,19 @@
-use antler_camera::{Camera, Orthographic, Perspective};
+use std::collections::BTreeMap;
+
+use antler_camera::{
+    Camera, Convergence, Cubemap, CubemapLayout, Equirectangular, Exposure, Fisheye, FisheyeProjection, Framing,
+    Orthographic, Perspective, Stereo, StereoLayout,
+};
 use serde::{Deserialize, Serialize};
 
-use crate::vec3::Vec3;
+use crate::{
+    errors::ConfigError,
+    lens_config::{ApertureConfig, FocusConfig, LensConfig},
+    optics_config::OpticsConfig,
+    vec3::Vec3,
+};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub enum CameraConfig {
     Orthographic {
@@ -23,12 +33,240 @@ pub enum CameraConfig {
         up: Vec3,
         #[serde(default = "default_vertical_fov")]
         vertical_fov: f32,
+        #[serde(default)]
+        lens: Option<LensConfig>,
+        #[serde(default)]
+        optics: Option<OpticsConfig>,
+    },
+    Physical {
+        position: Vec3,
+        #[serde(default = "default_look_at")]
+        look_at: Vec3,
+        #[serde(default = "default_up")]
+        up: Vec3,
+        #[serde(default = "default_focal_length")]
+        focal_length: f32,
+        // Only the height sets the field of view; the image resolution decides how much of the width is seen.
+        #[serde(default = "default_sensor_height")]
+        sensor_height: f32,
+        #[serde(default = "default_f_stop")]
+        f_stop: f32,
+        #[serde(default = "default_shutter")]
+        shutter: f32,
+        #[serde(default = "default_iso")]
+        iso: f32,
+        #[serde(default)]
+        exposure_compensation: f32,
+        #[serde(default = "default_depth_of_field")]
+        depth_of_field: bool,
+        #[serde(default)]
+        focus: FocusConfig,
+        #[serde(default)]
+        aperture: ApertureConfig,
+        #[serde(default)]
+        optics: Option<OpticsConfig>,
+    },
+    Equirectangular {
+        position: Vec3,
+        #[serde(default = "default_look_at")]
+        look_at: Vec3,
+        #[serde(default = "default_up")]
+        up: Vec3,
     },
+    Fisheye {
+        position: Vec3,
+        #[serde(default = "default_look_at")]
+        look_at: Vec3,
+        #[serde(default = "default_up")]
+        up: Vec3,
+        #[serde(default = "default_fisheye_fov")]
+        fov: f32,
+        #[serde(default)]
+        projection: FisheyeProjectionConfig,
+    },
+    Cubemap {
+        position: Vec3,
+        #[serde(default = "default_look_at")]
+        look_at: Vec3,
+        #[serde(default = "default_up")]
+        up: Vec3,
+        #[serde(default)]
+        layout: CubemapLayoutConfig,
+    },
+    Stereo {
+        camera: Box<Self>,
+        #[serde(default = "default_interpupillary_distance")]
+        interpupillary_distance: f32,
+        convergence: ConvergenceConfig,
+        #[serde(default)]
+        layout: StereoLayoutConfig,
+    },
+    // A camera imported from a glTF file, named by the node carrying it.
+    Gltf {
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum FisheyeProjectionConfig {
+    #[default]
+    Equidistant,
+    Equisolid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum CubemapLayoutConfig {
+    #[default]
+    Cross,
+    Strip,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum ConvergenceConfig {
+    ToeIn { distance: f32 },
+    OffAxis { distance: f32 },
+    Omnidirectional,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum StereoLayoutConfig {
+    #[default]
+    SideBySide,
+    TopBottom,
 }
 
 impl CameraConfig {
-    pub fn build(self) -> Camera {
+    // Swaps references to imported glTF cameras for the cameras themselves, before anything else reads them.
+    pub fn resolve(&mut self, imported: &BTreeMap<String, Self>) -> Result<(), ConfigError> {
+        match self {
+            Self::Gltf { name } => {
+                *self = imported.get(name).cloned().ok_or_else(|| {
+                    ConfigError::ParseError(format!("Capture refers to unknown glTF camera '{name}'"))
+                })?;
+                Ok(())
+            }
+            Self::Stereo { camera, .. } => camera.resolve(imported),
+            Self::Orthographic { .. }
+            | Self::Perspective { .. }
+            | Self::Physical { .. }
+            | Self::Equirectangular { .. }
+            | Self::Fisheye { .. }
+            | Self::Cubemap { .. } => Ok(()),
+        }
+    }
+
+    pub fn aim(&mut self, target_position: Vec3, target_look_at: Vec3) {
+        match self {
+            Self::Orthographic { position, look_at, .. }
+            | Self::Perspective { position, look_at, .. }
+            | Self::Physical { position, look_at, .. }
+            | Self::Equirectangular { position, look_at, .. }
+            | Self::Fisheye { position, look_at, .. }
+            | Self::Cubemap { position, look_at, .. } => {
+                (*position, *look_at) = (target_position, target_look_at);
+            }
+            Self::Stereo { camera, .. } => camera.aim(target_position, target_look_at),
+            Self::Gltf { .. } => unreachable!("{UNRESOLVED_GLTF}"),
+        }
+    }
+
+    pub fn view(&self) -> (Vec3, Vec3) {
+        match self {
+            Self::Orthographic { position, look_at, .. }
+            | Self::Perspective { position, look_at, .. }
+            | Self::Physical { position, look_at, .. }
+            | Self::Equirectangular { position, look_at, .. }
+            | Self::Fisheye { position, look_at, .. }
+            | Self::Cubemap { position, look_at, .. } => (*position, *look_at),
+            Self::Stereo { camera, .. } => camera.view(),
+            Self::Gltf { .. } => unreachable!("{UNRESOLVED_GLTF}"),
+        }
+    }
+
+    pub fn set_fov(&mut self, degrees: f32) -> bool {
+        match self {
+            Self::Perspective { vertical_fov: fov, .. } | Self::Fisheye { fov, .. } => {
+                *fov = degrees;
+                true
+            }
+            Self::Stereo { camera, .. } => camera.set_fov(degrees),
+            Self::Gltf { .. } => unreachable!("{UNRESOLVED_GLTF}"),
+            Self::Orthographic { .. } | Self::Physical { .. } | Self::Equirectangular { .. } | Self::Cubemap { .. } => {
+                false
+            }
+        }
+    }
+
+    pub fn frame(&mut self, framing: &Framing, aspect_ratio: f32) {
         match self {
+            Self::Orthographic {
+                position,
+                look_at,
+                up,
+                size,
+            } => {
+                let (framed_position, framed_look_at, framed_size) =
+                    framing.orthographic((*position).into(), (*look_at).into(), (*up).into(), aspect_ratio);
+                (*position, *look_at, *size) = (framed_position.into(), framed_look_at.into(), framed_size);
+            }
+            Self::Perspective {
+                position,
+                look_at,
+                up,
+                vertical_fov,
+                ..
+            } => {
+                let (framed_position, framed_look_at) = framing.perspective(
+                    (*position).into(),
+                    (*look_at).into(),
+                    (*up).into(),
+                    vertical_fov.to_radians(),
+                    aspect_ratio,
+                );
+                (*position, *look_at) = (framed_position.into(), framed_look_at.into());
+            }
+            Self::Physical {
+                position,
+                look_at,
+                up,
+                focal_length,
+                sensor_height,
+                ..
+            } => {
+                let (framed_position, framed_look_at) = framing.perspective(
+                    (*position).into(),
+                    (*look_at).into(),
+                    (*up).into(),
+                    physical_vertical_fov(*sensor_height, *focal_length),
+                    aspect_ratio,
+                );
+                (*position, *look_at) = (framed_position.into(), framed_look_at.into());
+            }
+            Self::Fisheye {
+                position, look_at, fov, ..
+            } => {
+                let (framed_position, framed_look_at) =
+                    framing.cone((*position).into(), (*look_at).into(), fov.to_radians() * 0.5);
+                (*position, *look_at) = (framed_position.into(), framed_look_at.into());
+            }
+            // Panoramas see everything already, so they are only turned to face the bounds.
+            Self::Equirectangular { look_at, .. } | Self::Cubemap { look_at, .. } => {
+                *look_at = framing.bounds.centroid().into();
+            }
+            Self::Stereo { camera, layout, .. } => {
+                let eye_aspect_ratio = match layout {
+                    StereoLayoutConfig::SideBySide => aspect_ratio * 0.5,
+                    StereoLayoutConfig::TopBottom => aspect_ratio * 2.0,
+                };
+                camera.frame(framing, eye_aspect_ratio);
+            }
+            Self::Gltf { .. } => unreachable!("{UNRESOLVED_GLTF}"),
+        }
+    }
+
+    pub fn build(self) -> Result<Camera, ConfigError> {
+        Ok(match self {
             Self::Orthographic {
                 position,
                 look_at,
@@ -40,11 +278,144 @@ impl CameraConfig {
                 look_at,
                 up,
                 vertical_fov,
-            } => Perspective::new(position.into(), look_at.into(), up.into(), vertical_fov.to_radians()).into(),
+                lens,
+                optics,
+            } => {
+                let (position, look_at) = (position.into(), look_at.into());
+                let mut camera = Perspective::new(position, look_at, up.into(), vertical_fov.to_radians());
+
+                if let Some(lens) = lens {
+                    camera = camera.with_lens(lens.build(position, look_at)?);
+                }
+                if let Some(optics) = optics {
+                    camera = camera.with_optics(optics.build());
+                }
+
+                camera.into()
+            }
+            Self::Physical {
+                position,
+                look_at,
+                up,
+                focal_length,
+                sensor_height,
+                f_stop,
+                shutter,
+                iso,
+                exposure_compensation,
+                depth_of_field,
+                focus,
+                aperture,
+                optics,
+            } => {
+                let vertical_fov = physical_vertical_fov(sensor_height, focal_length);
+                let exposure = Exposure::new(f_stop, shutter, iso, exposure_compensation);
+
+                let (position, look_at) = (position.into(), look_at.into());
+                let mut camera =
+                    Perspective::new(position, look_at, up.into(), vertical_fov).with_exposure(exposure.scale());
+
+                // Focal length and sensor height are in millimetres, the scene in metres.
+                if depth_of_field {
+                    let aperture_radius = focal_length / f_stop * 0.5 * MILLIMETRES_TO_METRES;
+                    let lens = LensConfig {
+                        aperture_radius,
+                        focus,
+                        aperture,
+                    };
+                    camera = camera.with_lens(lens.build(position, look_at)?);
+                }
+                if let Some(optics) = optics {
+                    camera = camera.with_optics(optics.build());
+                }
+
+                camera.into()
+            }
+            Self::Equirectangular { position, look_at, up } => {
+                Equirectangular::new(position.into(), look_at.into(), up.into()).into()
+            }
+            Self::Fisheye {
+                position,
+                look_at,
+                up,
+                fov,
+                projection,
+            } => Fisheye::new(
+                position.into(),
+                look_at.into(),
+                up.into(),
+                fov.to_radians(),
+                projection.build(),
+            )
+            .into(),
+            Self::Cubemap {
+                position,
+                look_at,
+                up,
+                layout,
+            } => Cubemap::new(position.into(), look_at.into(), up.into(), layout.build()).into(),
+            Self::Stereo {
+                camera,
+                interpupillary_distance,
+                convergence,
+                layout,
+            } => Stereo::new(
+                camera.build()?,
+                interpupillary_distance,
+                convergence.build(),
+                layout.build(),
+            )
+            .into(),
+            Self::Gltf { .. } => unreachable!("{UNRESOLVED_GLTF}"),
+        })
+    }
+}
+
+impl FisheyeProjectionConfig {
+    const fn build(self) -> FisheyeProjection {
+        match self {
+            Self::Equidistant => FisheyeProjection::Equidistant,
+            Self::Equisolid => FisheyeProjection::Equisolid,
+        }
+    }
+}
+
+impl CubemapLayoutConfig {
+    const fn build(self) -> CubemapLayout {
+        match self {
+            Self::Cross => CubemapLayout::Cross,
+            Self::Strip => CubemapLayout::Strip,
         }
     }
 }
 
+impl ConvergenceConfig {
+    const fn build(self) -> Convergence {
+        match self {
+            Self::ToeIn { distance } => Convergence::ToeIn { distance },
+            Self::OffAxis { distance } => Convergence::OffAxis { distance },
+            Self::Omnidirectional => Convergence::Omnidirectional,
+        }
+    }
+}
+
+impl StereoLayoutConfig {
+    const fn build(self) -> StereoLayout {
+        match self {
+            Self::SideBySide => StereoLayout::SideBySide,
+            Self::TopBottom => StereoLayout::TopBottom,
+        }
+    }
+}
+
+const MILLIMETRES_TO_METRES: f32 = 1.0e-3;
+
+const UNRESOLVED_GLTF: &str = "glTF cameras are resolved when their scene is built";
+
+fn physical_vertical_fov(sensor_height: f32, focal_length: f32) -> f32 {
+    2.0 * (sensor_height / (2.0 * focal_length)).atan()
+}
+
 const fn default_look_at() -> Vec3 {
     Vec3::new(0.0, 0.0, 0.0)
 }
@@ -60,3 +431,35 @@ const fn default_size() -> [f32; 2] {
 const fn default_vertical_fov() -> f32 {
     45.0
 }
+
+const fn default_focal_length() -> f32 {
+    50.0
+}
+
+const fn default_sensor_height() -> f32 {
+    24.0
+}
+
+const fn default_f_stop() -> f32 {
+    8.0
+}
+
+const fn default_shutter() -> f32 {
+    1.0 / 125.0
+}
+
+const fn default_iso() -> f32 {
+    100.0
+}
+
+const fn default_depth_of_field() -> bool {
+    true
+}
+
+const fn default_fisheye_fov() -> f32 {
+    180.0
+}
+
+const fn default_interpupillary_distance() -> f32 {
+    0.064
+}
@@ -1,26 +1,104 @@
 use std::collections::BTreeMap;
 
+use antler_camera::{Camera, MotionBlur};
+use antler_id::ObjectId;
 use antler_parameters::CaptureParameters;
+use antler_scene::{Resources, Scene};
 use serde::{Deserialize, Serialize};
 
-use crate::{camera_config::CameraConfig, image_config::ImageConfig};
+use crate::{
+    camera_config::CameraConfig, errors::ConfigError, framing_config::FramingConfig, image_config::ImageConfig,
+    orbit_config::OrbitConfig, shutter_config::ShutterConfig,
+};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct CaptureConfig {
     pub camera: CameraConfig,
+    #[serde(default)]
+    pub framing: Option<FramingConfig>,
+    #[serde(default)]
+    pub orbit: Option<OrbitConfig>,
+    #[serde(default)]
+    pub shutter: Option<ShutterConfig>,
     pub images: BTreeMap<String, ImageConfig>,
 }
 
 impl CaptureConfig {
-    pub fn build(self) -> CaptureParameters {
-        CaptureParameters {
-            camera: self.camera.build(),
+    pub fn build(
+        self,
+        scene: &Scene,
+        resources: &Resources,
+        object_names: &BTreeMap<String, ObjectId>,
+    ) -> Result<CaptureParameters, ConfigError> {
+        Ok(CaptureParameters {
+            cameras: self.cameras(scene, resources, object_names)?,
+            sequence: self.orbit.as_ref().map(OrbitConfig::sequence),
             images: self
                 .images
                 .into_iter()
-                .map(|(name, image)| (name, image.build()))
+                .map(|(name, image)| Ok((name, image.build()?)))
+                .collect::<Result<_, ConfigError>>()?,
+        })
+    }
+
+    pub fn cameras(
+        &self,
+        scene: &Scene,
+        resources: &Resources,
+        object_names: &BTreeMap<String, ObjectId>,
+    ) -> Result<Vec<Camera>, ConfigError> {
+        // Fitting the narrowest image keeps the bounds in frame for every image of the capture.
+        let aspect_ratio = self
+            .images
+            .values()
+            .map(|image| image.resolution[0] as f32 / image.resolution[1] as f32)
+            .reduce(f32::min)
+            .unwrap_or(1.0);
+
+        let framing = self
+            .framing
+            .as_ref()
+            .map(|framing| framing.build(scene, resources, object_names))
+            .transpose()?;
+
+        let views = match &self.orbit {
+            Some(orbit) => orbit
+                .positions()?
+                .into_iter()
+                .map(|position| Some((position, orbit.target)))
                 .collect(),
-        }
+            None => vec![None],
+        };
+
+        views
+            .into_iter()
+            .map(|view| {
+                let place = |camera: &CameraConfig| {
+                    let mut camera = camera.clone();
+
+                    if let Some((position, look_at)) = view {
+                        camera.aim(position, look_at);
+                    }
+                    if let Some(framing) = &framing {
+                        camera.frame(framing, aspect_ratio);
+                    }
+
+                    camera.build()
+                };
+
+                let camera = place(&self.camera)?;
+                let Some(shutter) = &self.shutter else {
+                    return Ok(camera);
+                };
+
+                let end_transform = shutter
+                    .end_camera(&self.camera)
+                    .map(|end_camera| place(&end_camera).map(|end_camera| *end_camera.transform()))
+                    .transpose()?;
+
+                Ok(MotionBlur::new(camera, [shutter.open, shutter.close], end_transform).into())
+            })
+            .collect()
     }
 }
@@ -0,0 +1,52 @@
+use antler_settings::{Crop, CropOutput, CropWindow};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ConfigError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CropConfig {
+    pub window: CropWindowConfig,
+    #[serde(default)]
+    pub output: CropOutputConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum CropWindowConfig {
+    Pixels { min: [usize; 2], max: [usize; 2] },
+    Normalised { min: [f32; 2], max: [f32; 2] },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum CropOutputConfig {
+    #[default]
+    Cropped,
+    Full,
+    Paste,
+}
+
+impl CropConfig {
+    pub fn build(self, resolution: [usize; 2]) -> Result<Crop, ConfigError> {
+        let window = match self.window {
+            CropWindowConfig::Pixels { min, max } => CropWindow::Pixels { min, max },
+            CropWindowConfig::Normalised { min, max } => CropWindow::Normalised { min, max },
+        };
+
+        if window.checked_pixel_bounds(resolution).is_none() {
+            return Err(ConfigError::ParseError(format!(
+                "Crop window {window:?} does not cover any pixel of a {}x{} image",
+                resolution[0], resolution[1]
+            )));
+        }
+
+        Ok(Crop {
+            window,
+            output: match self.output {
+                CropOutputConfig::Cropped => CropOutput::Cropped,
+                CropOutputConfig::Full => CropOutput::Full,
+                CropOutputConfig::Paste => CropOutput::Paste,
+            },
+        })
+    }
+}
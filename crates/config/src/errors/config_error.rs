@@ -5,7 +5,8 @@ use std::{
     path::PathBuf,
 };
 
-use antler_geometry::errors::MeshLoadError;
+use antler_geometry::errors::{CsgError, MeshLoadError, SdfError};
+use antler_gltf::errors::GltfError;
 use antler_image::errors::ImageLoadError;
 use ron::error::SpannedError;
 
@@ -17,7 +18,11 @@ pub enum ConfigError {
     Include(IncludeError),
     AssetLoadError { path: PathBuf, message: String },
     MeshLoad(MeshLoadError),
+    Sdf(SdfError),
+    Csg(CsgError),
     ImageLoad(ImageLoadError),
+    Gltf(GltfError),
+    Animation(String),
 }
 
 impl From<IoError> for ConfigError {
@@ -44,12 +49,30 @@ impl From<MeshLoadError> for ConfigError {
     }
 }
 
+impl From<SdfError> for ConfigError {
+    fn from(value: SdfError) -> Self {
+        Self::Sdf(value)
+    }
+}
+
+impl From<CsgError> for ConfigError {
+    fn from(value: CsgError) -> Self {
+        Self::Csg(value)
+    }
+}
+
 impl From<ImageLoadError> for ConfigError {
     fn from(value: ImageLoadError) -> Self {
         Self::ImageLoad(value)
     }
 }
 
+impl From<GltfError> for ConfigError {
+    fn from(value: GltfError) -> Self {
+        Self::Gltf(value)
+    }
+}
+
 impl Display for ConfigError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -59,7 +82,11 @@ impl Display for ConfigError {
                 write!(f, "Failed to load asset at '{}': {}", path.display(), message)
             }
             Self::MeshLoad(err) => write!(f, "{err}"),
+            Self::Sdf(err) => write!(f, "{err}"),
+            Self::Csg(err) => write!(f, "{err}"),
             Self::ImageLoad(err) => write!(f, "{err}"),
+            Self::Gltf(err) => write!(f, "{err}"),
+            Self::Animation(err) => write!(f, "Config animation error: {err}"),
         }
     }
 }
@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+
+use antler_camera::Framing;
+use antler_geometry::Aabb;
+use antler_id::ObjectId;
+use antler_scene::{Resources, Scene};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ConfigError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FramingConfig {
+    #[serde(default = "default_margin")]
+    pub margin: f32,
+    #[serde(default)]
+    pub objects: Vec<String>,
+}
+
+impl FramingConfig {
+    pub fn build(
+        &self,
+        scene: &Scene,
+        resources: &Resources,
+        object_names: &BTreeMap<String, ObjectId>,
+    ) -> Result<Framing, ConfigError> {
+        if self.objects.is_empty() {
+            return Ok(Framing::new(scene.bounds(), self.margin));
+        }
+
+        let bounds = self
+            .objects
+            .iter()
+            .map(|name| {
+                object_names
+                    .get(name)
+                    .map(|&object_id| scene.object_bounds(resources, object_id))
+                    .ok_or_else(|| ConfigError::ParseError(format!("Framing refers to unknown object '{name}'")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Framing::new(Aabb::union(bounds.into_iter()), self.margin))
+    }
+}
+
+const fn default_margin() -> f32 {
+    0.05
+}
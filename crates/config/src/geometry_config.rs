@@ -1,9 +1,13 @@
 use std::path::PathBuf;
 
-use antler_geometry::{Aabb, Capsule, Circle, Geometry, Mesh, Quad, Sphere, Torus, Triangle};
+use antler_geometry::{
+    Aabb, Annulus, Capsule, Circle, Cone, Csg, CsgOperation, Cylinder, Geometry, Heightfield, Mesh, Quad, Sdf, Sphere,
+    Torus, Triangle,
+};
+use antler_image::load_height_map;
 use serde::{Deserialize, Serialize};
 
-use crate::{errors::ConfigError, vec2::Vec2, vec3::Vec3};
+use crate::{errors::ConfigError, mesh_repair_config::MeshRepairConfig, sdf_config::SdfConfig, vec2::Vec2, vec3::Vec3};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -12,6 +16,16 @@ pub enum GeometryConfig {
         min: Vec3,
         max: Vec3,
     },
+    Annulus {
+        #[serde(default = "default_centre")]
+        centre: Vec3,
+        #[serde(default = "default_normal")]
+        normal: Vec3,
+        #[serde(default = "default_inner_radius")]
+        inner_radius: f32,
+        #[serde(default = "default_radius")]
+        outer_radius: f32,
+    },
     Capsule {
         #[serde(default = "default_start_position")]
         a: Vec3,
@@ -28,8 +42,44 @@ pub enum GeometryConfig {
         #[serde(default = "default_radius")]
         radius: f32,
     },
+    Cone {
+        #[serde(default = "default_start_position")]
+        a: Vec3,
+        #[serde(default = "default_end_position")]
+        b: Vec3,
+        #[serde(default = "default_radius")]
+        radius_a: f32,
+        #[serde(default)]
+        radius_b: f32,
+        #[serde(default = "default_caps")]
+        caps: bool,
+    },
+    Csg {
+        operation: CsgOperationConfig,
+        a: Box<Self>,
+        b: Box<Self>,
+    },
+    Cylinder {
+        #[serde(default = "default_start_position")]
+        a: Vec3,
+        #[serde(default = "default_end_position")]
+        b: Vec3,
+        #[serde(default = "default_radius")]
+        radius: f32,
+        #[serde(default = "default_caps")]
+        caps: bool,
+    },
+    // A greyscale height map stretched over `size` in x and y, with white raised to `height`.
+    Heightfield {
+        path: PathBuf,
+        size: Vec2,
+        height: f32,
+    },
+    // OBJ, PLY or STL, optionally cleaned up as it loads.
     Mesh {
         path: PathBuf,
+        #[serde(default)]
+        repair: Option<MeshRepairConfig>,
     },
     Quad {
         #[serde(default = "default_centre")]
@@ -39,6 +89,9 @@ pub enum GeometryConfig {
         #[serde(default = "default_size")]
         size: Vec2,
     },
+    Sdf {
+        root: SdfConfig,
+    },
     Sphere {
         #[serde(default = "default_centre")]
         centre: Vec3,
@@ -64,10 +117,49 @@ impl GeometryConfig {
     pub fn build(self) -> Result<Geometry, ConfigError> {
         Ok(match self {
             Self::Aabb { min, max } => Aabb::new(min.into(), max.into()).into(),
+            Self::Annulus {
+                centre,
+                normal,
+                inner_radius,
+                outer_radius,
+            } => Annulus::new(centre.into(), normal.into(), inner_radius, outer_radius).into(),
             Self::Capsule { a, b, radius } => Capsule::new(a.into(), b.into(), radius).into(),
             Self::Circle { centre, normal, radius } => Circle::new(centre.into(), normal.into(), radius).into(),
-            Self::Mesh { path } => Mesh::load(path)?.into(),
+            Self::Cone {
+                a,
+                b,
+                radius_a,
+                radius_b,
+                caps,
+            } => Cone::new(a.into(), b.into(), radius_a, radius_b, caps).into(),
+            Self::Csg { operation, a, b } => Csg::new(operation.build(), a.build()?, b.build()?)?.into(),
+            Self::Cylinder { a, b, radius, caps } => Cylinder::new(a.into(), b.into(), radius, caps).into(),
+            Self::Heightfield { path, size, height } => {
+                if !size.0.iter().all(|extent| extent.is_finite() && *extent > 0.0) || !height.is_finite() {
+                    return Err(ConfigError::ParseError(format!(
+                        "Heightfield needs a positive size and a finite height, got {:?} and {height}",
+                        size.0
+                    )));
+                }
+
+                let heights = load_height_map(&path)?;
+                let [columns, rows] = heights.size();
+                if columns < 2 || rows < 2 {
+                    return Err(ConfigError::AssetLoadError {
+                        path,
+                        message: format!("Heightfield needs at least 2x2 samples, got {columns}x{rows}"),
+                    });
+                }
+
+                Heightfield::new(heights, size.0, height).into()
+            }
+            Self::Mesh { path, repair: None } => Mesh::load(path)?.into(),
+            Self::Mesh {
+                path,
+                repair: Some(repair),
+            } => Mesh::load_repaired(path, &repair.build()?)?.into(),
             Self::Quad { centre, normal, size } => Quad::new(centre.into(), normal.into(), size.into()).into(),
+            Self::Sdf { root } => Sdf::new(root.build())?.into(),
             Self::Sphere { centre, radius } => Sphere::new(centre.into(), radius).into(),
             Self::Torus {
                 centre,
@@ -84,6 +176,23 @@ impl GeometryConfig {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum CsgOperationConfig {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl CsgOperationConfig {
+    const fn build(self) -> CsgOperation {
+        match self {
+            Self::Union => CsgOperation::Union,
+            Self::Intersection => CsgOperation::Intersection,
+            Self::Difference => CsgOperation::Difference,
+        }
+    }
+}
+
 const fn default_centre() -> Vec3 {
     Vec3::new(0.0, 0.0, 0.0)
 }
@@ -104,6 +213,14 @@ const fn default_radius() -> f32 {
     1.0
 }
 
+const fn default_inner_radius() -> f32 {
+    0.5
+}
+
+const fn default_caps() -> bool {
+    true
+}
+
 const fn default_major_radius() -> f32 {
     1.0
 }
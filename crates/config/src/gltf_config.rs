@@ -0,0 +1,175 @@
+use std::{
+    collections::{BTreeMap, HashMap, hash_map::Entry},
+    f32::consts::FRAC_PI_2,
+    path::PathBuf,
+};
+
+use antler_geometry::Mesh;
+use antler_gltf::{Gltf, GltfLightKind, GltfProjection, similarity};
+use antler_id::{GeometryId, MaterialId, ShaderId};
+use antler_material::{Ggx, Material, Opaque};
+use antler_scene::{Emissive, Object, Resources, Scene};
+use antler_shader::{Luminous, Shader, Solid, Textured, VertexColour};
+use nalgebra::{Matrix4, Point3, Rotation3, Similarity3, Vector3};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera_config::CameraConfig, errors::ConfigError, light_config::LightConfig, transform::Transform, vec3::Vec3,
+};
+
+// Reflectance of a glTF dielectric at normal incidence; metals reflect fully.
+const DIELECTRIC_REFLECTANCE: f32 = 0.04;
+
+// Pulls a glTF file's default scene, or one named node, in alongside the hand-authored objects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GltfConfig {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub node: Option<String>,
+    #[serde(default)]
+    pub transform: Transform,
+}
+
+// A loaded file with the transform that places its y-up coordinates in antler's z-up world.
+pub struct GltfImport {
+    gltf: Gltf,
+    root: Matrix4<f32>,
+}
+
+impl GltfConfig {
+    pub fn load(&self) -> Result<GltfImport, ConfigError> {
+        let y_up = Rotation3::from_axis_angle(&Vector3::x_axis(), FRAC_PI_2);
+
+        Ok(GltfImport {
+            gltf: Gltf::load(&self.path, self.node.as_deref())?,
+            root: Similarity3::from(self.transform.clone()).to_homogeneous() * y_up.to_homogeneous(),
+        })
+    }
+}
+
+impl GltfImport {
+    // Captures refer to imported cameras by the name of the node carrying them.
+    pub fn cameras(&self) -> impl Iterator<Item = (String, CameraConfig)> + '_ {
+        self.gltf.cameras.iter().map(|camera| {
+            let transform = self.root * camera.transform;
+
+            let position = transform.transform_point(&Point3::origin());
+            let forward = transform.transform_vector(&-Vector3::z()).normalize();
+            let up = transform.transform_vector(&Vector3::y());
+            let scale = up.norm();
+
+            let (look_at, position, up) = ((position + forward).into(), position.into(), Vec3::from(up / scale));
+            let config = match camera.projection {
+                GltfProjection::Perspective { vertical_fov } => CameraConfig::Perspective {
+                    position,
+                    look_at,
+                    up,
+                    vertical_fov: vertical_fov.to_degrees(),
+                    lens: None,
+                    optics: None,
+                },
+                GltfProjection::Orthographic { size } => CameraConfig::Orthographic {
+                    position,
+                    look_at,
+                    up,
+                    size: size.map(|extent| extent * scale),
+                },
+            };
+
+            (camera.name.clone(), config)
+        })
+    }
+
+    pub fn lights(&self) -> impl Iterator<Item = LightConfig> + '_ {
+        self.gltf.lights.iter().map(|light| {
+            let transform = self.root * light.transform;
+
+            match light.kind {
+                GltfLightKind::Directional => LightConfig::Directional {
+                    direction: transform.transform_vector(&-Vector3::z()).into(),
+                    colour: light.colour * light.intensity,
+                    angular_radius: None,
+                    samples: None,
+                },
+                GltfLightKind::Point => LightConfig::Point {
+                    position: transform.transform_point(&Point3::origin()).into(),
+                    colour: light.colour,
+                    intensity: light.intensity,
+                    angular_radius: None,
+                    samples: None,
+                },
+            }
+        })
+    }
+
+    // Nodes placed by a similarity share one mesh per primitive; sheared or mirrored ones get their own baked copy.
+    pub fn build(&self, scene: &mut Scene, resources: &mut Resources) -> Result<(), ConfigError> {
+        let mut meshes: HashMap<usize, GeometryId> = HashMap::new();
+        let mut appearances = BTreeMap::new();
+
+        for object in &self.gltf.objects {
+            let primitive = &self.gltf.primitives[object.primitive];
+            let transform = self.root * object.transform;
+
+            let (shader_id, material_id, emissive) = *appearances
+                .entry((primitive.material, primitive.has_colours()))
+                .or_insert_with(|| self.appearance(resources, primitive.material, primitive.has_colours()));
+
+            let (geometry_id, transform) = if let Some(similarity) = similarity(&transform) {
+                let geometry_id = match meshes.entry(object.primitive) {
+                    Entry::Occupied(entry) => *entry.get(),
+                    Entry::Vacant(entry) => {
+                        let mesh = Mesh::new(primitive.buffers(&Matrix4::identity()))?;
+                        *entry.insert(resources.add_geometry(mesh))
+                    }
+                };
+                (geometry_id, similarity)
+            } else {
+                let geometry_id = resources.add_geometry(Mesh::new(primitive.buffers(&transform))?);
+                (geometry_id, Similarity3::identity())
+            };
+
+            scene.add_object(Object::new(geometry_id, shader_id, material_id, emissive, transform));
+        }
+
+        Ok(())
+    }
+
+    // Emissive materials glow in their emissive colour; otherwise a texture wins over vertex colours over the base colour.
+    fn appearance(
+        &self,
+        resources: &mut Resources,
+        material: Option<usize>,
+        vertex_colours: bool,
+    ) -> (ShaderId, MaterialId, Option<Emissive>) {
+        let material = self.gltf.material(material);
+        let emitting = material.emissive.luminance() > 0.0;
+
+        let shader: Shader = if emitting {
+            Luminous::new(material.emissive, 1.0).into()
+        } else if let Some(texture) = material.base_colour_texture {
+            Textured::new(self.gltf.images[texture].clone()).into()
+        } else if vertex_colours {
+            VertexColour::new(material.base_colour).into()
+        } else {
+            Solid::new(material.base_colour).into()
+        };
+
+        // Fully rough dielectrics scatter nothing worth tracing, so they stay matte.
+        let bsdf: Material = if material.metallic <= 0.0 && material.roughness >= 1.0 {
+            Opaque::new().into()
+        } else {
+            let reflectance = material
+                .metallic
+                .mul_add(1.0 - DIELECTRIC_REFLECTANCE, DIELECTRIC_REFLECTANCE);
+            Ggx::new(material.roughness, reflectance).into()
+        };
+
+        (
+            resources.add_shader(shader),
+            resources.add_material(bsdf),
+            emitting.then(|| Emissive::new(material.emissive, 1.0, 1)),
+        )
+    }
+}
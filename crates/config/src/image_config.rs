@@ -2,7 +2,9 @@ use antler_colour::Rgba;
 use antler_settings::ImageSettings;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::{crop_config::CropConfig, errors::ConfigError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ImageConfig {
     #[serde(default = "default_background")]
@@ -12,16 +14,22 @@ pub struct ImageConfig {
     pub tile_size: [usize; 2],
     #[serde(default = "default_super_samples")]
     pub super_samples: usize,
+    #[serde(default)]
+    pub crop: Option<CropConfig>,
+    #[serde(default)]
+    pub stream: bool,
 }
 
 impl ImageConfig {
-    pub const fn build(self) -> ImageSettings {
-        ImageSettings {
+    pub fn build(self) -> Result<ImageSettings, ConfigError> {
+        Ok(ImageSettings {
             background: self.background,
             resolution: self.resolution,
             tile_size: self.tile_size,
             super_samples: self.super_samples,
-        }
+            crop: self.crop.map(|crop| crop.build(self.resolution)).transpose()?,
+            stream: self.stream,
+        })
     }
 }
 
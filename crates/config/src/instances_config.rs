@@ -0,0 +1,30 @@
+use std::collections::BTreeMap;
+
+use antler_id::ObjectId;
+use antler_scene::Scene;
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ConfigError, transform::Transform};
+
+// Places copies of a named object that share its geometry, shader and material, so each costs only a transform.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstancesConfig {
+    pub object: String,
+    pub transforms: Vec<Transform>,
+}
+
+impl InstancesConfig {
+    pub fn build(self, scene: &mut Scene, object_names: &BTreeMap<String, ObjectId>) -> Result<(), ConfigError> {
+        let object_id = *object_names
+            .get(&self.object)
+            .ok_or_else(|| ConfigError::ParseError(format!("Instances refer to unknown object '{}'", self.object)))?;
+
+        for transform in self.transforms {
+            let instance = scene.get_object(object_id).instance(transform.into());
+            scene.add_object(instance);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use antler_camera::{Aperture, Lens};
+use nalgebra::Point3;
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ConfigError, vec3::Vec3};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LensConfig {
+    pub aperture_radius: f32,
+    #[serde(default)]
+    pub focus: FocusConfig,
+    #[serde(default)]
+    pub aperture: ApertureConfig,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum FocusConfig {
+    #[default]
+    LookAt,
+    Distance(f32),
+    Point(Vec3),
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum ApertureConfig {
+    #[default]
+    Circular,
+    Polygonal {
+        blades: usize,
+        #[serde(default)]
+        rotation: f32,
+    },
+}
+
+impl LensConfig {
+    pub fn build(self, position: Point3<f32>, look_at: Point3<f32>) -> Result<Lens, ConfigError> {
+        if !(self.aperture_radius.is_finite() && self.aperture_radius >= 0.0) {
+            return Err(ConfigError::ParseError(format!(
+                "Lens aperture radius must be finite and non-negative, got {}",
+                self.aperture_radius
+            )));
+        }
+
+        // A focus point level with or behind the camera projects onto the view axis at zero or less.
+        let focus_distance = self.focus.distance(position, look_at);
+        if !(focus_distance.is_finite() && focus_distance > 0.0) {
+            return Err(ConfigError::ParseError(format!(
+                "Lens focus must lie in front of the camera, got a focus distance of {focus_distance}"
+            )));
+        }
+
+        Ok(Lens::new(self.aperture_radius, focus_distance, self.aperture.build()?))
+    }
+}
+
+impl FocusConfig {
+    pub fn distance(&self, position: Point3<f32>, look_at: Point3<f32>) -> f32 {
+        match *self {
+            Self::LookAt => (look_at - position).norm(),
+            Self::Distance(distance) => distance,
+            Self::Point(point) => (Point3::from(point) - position).dot(&(look_at - position).normalize()),
+        }
+    }
+}
+
+impl ApertureConfig {
+    pub fn build(self) -> Result<Aperture, ConfigError> {
+        match self {
+            Self::Circular => Ok(Aperture::Circular),
+            Self::Polygonal { blades, .. } if blades < 3 => Err(ConfigError::ParseError(format!(
+                "Polygonal apertures need at least three blades, got {blades}"
+            ))),
+            Self::Polygonal { blades, rotation } => Ok(Aperture::polygonal(blades, rotation.to_radians())),
+        }
+    }
+}
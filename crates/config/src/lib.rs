@@ -1,23 +1,40 @@
+mod animation;
+mod animation_config;
 mod camera_config;
 mod capture_config;
+mod crop_config;
 mod emissive_config;
 pub mod errors;
+mod framing_config;
 mod geometry_config;
+mod gltf_config;
 mod gradient_config;
 mod image_config;
+mod instances_config;
+mod lens_config;
 mod light_config;
 mod lighting_config;
 mod manifest;
 mod material_config;
+mod mesh_repair_config;
+mod obj_config;
 mod object_config;
 mod occlusion_config;
+mod optics_config;
+mod orbit_config;
 mod probe_config;
+mod scatter_config;
 mod scene_config;
+mod sdf_config;
 mod shader_config;
+mod shutter_config;
 mod skybox_config;
+mod timeline_config;
+mod track;
 mod transform;
 mod utils;
 mod vec2;
 mod vec3;
 
+pub use animation::Animation;
 pub use manifest::Manifest;
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::vec3::Vec3;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub enum LightConfig {
     Directional {
@@ -35,6 +35,46 @@ pub enum LightConfig {
 }
 
 impl LightConfig {
+    pub const fn set_position(&mut self, value: Vec3) -> bool {
+        match self {
+            Self::Point { position, .. } => {
+                *position = value;
+                true
+            }
+            Self::Directional { .. } | Self::Environment { .. } => false,
+        }
+    }
+
+    pub const fn set_direction(&mut self, value: Vec3) -> bool {
+        match self {
+            Self::Directional { direction, .. } => {
+                *direction = value;
+                true
+            }
+            Self::Environment { .. } | Self::Point { .. } => false,
+        }
+    }
+
+    pub const fn set_colour(&mut self, value: Rgb) -> bool {
+        match self {
+            Self::Directional { colour, .. } | Self::Point { colour, .. } => {
+                *colour = value;
+                true
+            }
+            Self::Environment { .. } => false,
+        }
+    }
+
+    pub const fn set_intensity(&mut self, value: f32) -> bool {
+        match self {
+            Self::Point { intensity, .. } => {
+                *intensity = value;
+                true
+            }
+            Self::Directional { .. } | Self::Environment { .. } => false,
+        }
+    }
+
     pub fn build(self) -> Light {
         match self {
             Self::Directional {
@@ -9,8 +9,13 @@ use antler_scene::Resources;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::ConfigError, lighting_config::LightingConfig, probe_config::ProbeConfig, scene_config::SceneConfig,
-    utils::expand_includes,
+    animation::Animation,
+    errors::ConfigError,
+    lighting_config::LightingConfig,
+    probe_config::ProbeConfig,
+    scene_config::SceneConfig,
+    timeline_config::TimelineConfig,
+    utils::{expand_includes, fnv1a},
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -25,39 +30,61 @@ pub struct Manifest {
     pub lighting_settings: LightingConfig,
     #[serde(default)]
     pub probe_settings: ProbeConfig,
+    #[serde(default)]
+    pub timeline: Option<TimelineConfig>,
 }
 
 impl Manifest {
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
         let contents = read_to_string(&path)?;
+        Self::parse(&contents)
+    }
 
+    pub fn parse(contents: &str) -> Result<Self, ConfigError> {
         let cwd = std::env::current_dir()?;
-        let manifest_ron = expand_includes(&contents, cwd)?;
+        let manifest_ron = expand_includes(contents, cwd)?;
 
         let manifest = ron::from_str(&manifest_ron)?;
         Ok(manifest)
     }
 
+    pub fn fingerprint(&self) -> Result<u64, ConfigError> {
+        let canonical =
+            ron::to_string(self).map_err(|err| ConfigError::ParseError(format!("RON serialise error: {err}")))?;
+
+        Ok(fnv1a(canonical.as_bytes()))
+    }
+
     pub fn build(self) -> Result<SimulationParameters, ConfigError> {
+        self.build_animated().map(|(parameters, _animation)| parameters)
+    }
+
+    pub fn build_animated(self) -> Result<(SimulationParameters, Animation), ConfigError> {
         let mut resources = Resources::default();
+        let mut animations = BTreeMap::new();
 
         let scenes = self
             .scenes
             .into_iter()
             .map(|(name, scene)| {
-                let scene = scene.build(&mut resources)?;
+                let (scene, animation) = scene.build(&mut resources)?;
+                animations.insert(name.clone(), animation);
                 Ok((name, scene))
             })
             .collect::<Result<_, ConfigError>>()?;
 
-        Ok(SimulationParameters {
+        let animation = Animation::new(self.timeline, animations)?;
+
+        let parameters = SimulationParameters {
             assets_dir: self.assets_dir,
             output_dir: self.output_dir,
             resources,
             scenes,
             lighting_settings: self.lighting_settings.build(),
             probe_settings: self.probe_settings.build(),
-        })
+        };
+
+        Ok((parameters, animation))
     }
 }
 
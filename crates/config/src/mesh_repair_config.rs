@@ -0,0 +1,40 @@
+use antler_geometry::MeshRepair;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::ConfigError;
+
+// Every step is off unless asked for; the crease angle is in degrees.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MeshRepairConfig {
+    #[serde(default)]
+    pub weld: Option<f32>,
+    #[serde(default)]
+    pub drop_degenerate: bool,
+    #[serde(default)]
+    pub unify_winding: bool,
+    #[serde(default)]
+    pub crease_angle: Option<f32>,
+}
+
+impl MeshRepairConfig {
+    pub fn build(self) -> Result<MeshRepair, ConfigError> {
+        if let Some(weld) = self.weld.filter(|weld| !weld.is_finite() || *weld < 0.0) {
+            return Err(ConfigError::ParseError(format!(
+                "Mesh weld tolerance must be a finite distance of at least zero, got {weld}"
+            )));
+        }
+        if let Some(angle) = self.crease_angle.filter(|angle| !(0.0..=180.0).contains(angle)) {
+            return Err(ConfigError::ParseError(format!(
+                "Mesh crease angle must be between 0 and 180 degrees, got {angle}"
+            )));
+        }
+
+        Ok(MeshRepair {
+            weld_tolerance: self.weld,
+            drop_degenerate: self.drop_degenerate,
+            unify_winding: self.unify_winding,
+            crease_angle: self.crease_angle.map(f32::to_radians),
+        })
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    collections::{BTreeMap, btree_map::Entry},
+    path::{Path, PathBuf},
+};
+
+use antler_colour::Rgb;
+use antler_geometry::{Mesh, ObjMaterial, errors::MeshLoadError, load_obj_groups};
+use antler_id::{MaterialId, ShaderId};
+use antler_image::RgbImage;
+use antler_material::{Ggx, Material, Opaque, Refractive, Transparent};
+use antler_scene::{Emissive, Object, Resources, Scene};
+use antler_shader::{Luminous, Shader, Solid, Textured};
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ConfigError, transform::Transform};
+
+// Splits an OBJ into one object per model and material group, with materials taken from its MTL library.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ObjConfig {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub transform: Transform,
+}
+
+impl ObjConfig {
+    pub fn build(self, scene: &mut Scene, resources: &mut Resources) -> Result<(), ConfigError> {
+        let (groups, materials) = load_obj_groups(&self.path)?;
+        if groups.is_empty() {
+            return Err(MeshLoadError::EmptyMesh.into());
+        }
+
+        let directory = self.path.parent().unwrap_or_else(|| Path::new(""));
+        let transform = self.transform.into();
+
+        let mut appearances = BTreeMap::new();
+        for group in groups {
+            let (shader_id, material_id, emissive) = match appearances.entry(group.material) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let material = group.material.map(|index| &materials[index]);
+                    *entry.insert(appearance(resources, material, directory)?)
+                }
+            };
+
+            let geometry_id = resources.add_geometry(Mesh::new(group.buffers)?);
+            scene.add_object(Object::new(geometry_id, shader_id, material_id, emissive, transform));
+        }
+
+        Ok(())
+    }
+}
+
+// Emission wins over the diffuse texture, which wins over the diffuse colour.
+fn appearance(
+    resources: &mut Resources,
+    material: Option<&ObjMaterial>,
+    directory: &Path,
+) -> Result<(ShaderId, MaterialId, Option<Emissive>), ConfigError> {
+    let colour = |value: Option<[f32; 3]>| value.map(|[red, green, blue]| Rgb::new(red, green, blue));
+
+    let diffuse = material
+        .and_then(|material| colour(material.diffuse))
+        .unwrap_or(Rgb::WHITE);
+    let specular = material
+        .and_then(|material| colour(material.specular))
+        .unwrap_or(Rgb::BLACK);
+    let shininess = material.and_then(|material| material.shininess).unwrap_or(0.0);
+    let dissolve = material.and_then(|material| material.dissolve).unwrap_or(1.0);
+    let refractive_index = material.and_then(|material| material.optical_density);
+    let emission = material
+        .and_then(|material| {
+            let value = material.unknown_param.get("Ke")?;
+            Some(parse_colour(&material.name, value))
+        })
+        .transpose()?
+        .unwrap_or(Rgb::BLACK);
+
+    let emitting = emission.luminance() > 0.0;
+
+    let shader: Shader = if emitting {
+        Luminous::new(emission, 1.0).into()
+    } else if let Some(texture) = material.and_then(|material| material.diffuse_texture.as_ref()) {
+        Textured::new(RgbImage::load(directory.join(texture))?).into()
+    } else {
+        Solid::new(diffuse).into()
+    };
+
+    // See-through materials refract when given an index above air's; glossy ones take their roughness from Ns.
+    let bsdf: Material = if dissolve < 1.0 {
+        match refractive_index {
+            Some(refractive_index) if refractive_index > 1.0 => Refractive::new(refractive_index).into(),
+            _ => Transparent::new(1.0 - dissolve).into(),
+        }
+    } else if specular.luminance() > 0.0 {
+        Ggx::new((2.0 / (shininess.max(0.0) + 2.0)).sqrt(), specular.luminance()).into()
+    } else {
+        Opaque::new().into()
+    };
+
+    Ok((
+        resources.add_shader(shader),
+        resources.add_material(bsdf),
+        emitting.then(|| Emissive::new(emission, 1.0, 1)),
+    ))
+}
+
+// MTL colours are one grey value or three channels.
+fn parse_colour(material: &str, value: &str) -> Result<Rgb, ConfigError> {
+    let channels: Option<Vec<f32>> = value.split_whitespace().map(|channel| channel.parse().ok()).collect();
+
+    match channels.as_deref() {
+        Some(&[grey]) => Ok(Rgb::new(grey, grey, grey)),
+        Some(&[red, green, blue]) => Ok(Rgb::new(red, green, blue)),
+        _ => Err(ConfigError::ParseError(format!(
+            "Material '{material}' has an invalid colour '{value}'"
+        ))),
+    }
+}
@@ -9,13 +9,18 @@ use crate::{
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ObjectConfig {
+    #[serde(default)]
+    pub name: Option<String>,
     geometry: GeometryConfig,
-    shader: ShaderConfig,
+    pub shader: ShaderConfig,
     material: MaterialConfig,
     #[serde(default)]
     emissive: Option<EmissiveConfig>,
     #[serde(default)]
     transform: Transform,
+    // The transform at the end of the shutter interval, for motion blur.
+    #[serde(default)]
+    motion: Option<Transform>,
 }
 
 impl ObjectConfig {
@@ -25,12 +30,9 @@ impl ObjectConfig {
         let material_id = resources.add_material(self.material.build());
         let emissive = self.emissive.map(super::emissive_config::EmissiveConfig::build);
 
-        Ok(Object::new(
-            geometry_id,
-            shader_id,
-            material_id,
-            emissive,
-            self.transform.into(),
-        ))
+        Ok(
+            Object::new(geometry_id, shader_id, material_id, emissive, self.transform.into())
+                .with_end_transform(self.motion.map(Into::into)),
+        )
     }
 }
@@ -0,0 +1,31 @@
+use antler_camera::{Distortion, Optics};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpticsConfig {
+    #[serde(default)]
+    pub radial_distortion: [f32; 3],
+    #[serde(default)]
+    pub tangential_distortion: [f32; 2],
+    #[serde(default)]
+    pub vignetting: [f32; 3],
+    #[serde(default)]
+    pub chromatic_aberration: f32,
+}
+
+impl OpticsConfig {
+    pub fn build(self) -> Optics {
+        let distorted = self
+            .radial_distortion
+            .iter()
+            .chain(&self.tangential_distortion)
+            .any(|&coefficient| coefficient != 0.0);
+
+        Optics {
+            distortion: distorted.then(|| Distortion::new(self.radial_distortion, self.tangential_distortion)),
+            vignetting: self.vignetting,
+            chromatic_aberration: self.chromatic_aberration,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::f32::consts::TAU;
+
+use antler_settings::{ContactSheet, Sequence, SequenceAnimation};
+use nalgebra::{Unit, Vector3};
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ConfigError, vec3::Vec3};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrbitConfig {
+    pub frames: usize,
+    #[serde(default)]
+    pub target: Vec3,
+    pub radius: f32,
+    #[serde(default)]
+    pub elevation: f32,
+    #[serde(default)]
+    pub start_angle: f32,
+    #[serde(default = "default_axis")]
+    pub axis: Vec3,
+    #[serde(default)]
+    pub animation: Option<SequenceAnimationConfig>,
+    #[serde(default)]
+    pub contact_sheet: Option<ContactSheetConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SequenceAnimationConfig {
+    #[serde(default = "default_fps")]
+    pub fps: u16,
+    #[serde(default)]
+    pub plays: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContactSheetConfig {
+    #[serde(default)]
+    pub columns: Option<usize>,
+}
+
+impl OrbitConfig {
+    // Frames are spread over a whole turn, so the last frame leads back into the first.
+    pub fn positions(&self) -> Result<Vec<Vec3>, ConfigError> {
+        if self.frames == 0 {
+            return Err(ConfigError::ParseError("An orbit needs at least one frame".to_string()));
+        }
+
+        let axis = Unit::new_normalize(Vector3::from(self.axis));
+
+        // Angle zero faces along the x-axis, or the y-axis when orbiting about x.
+        let reference = if axis.x.abs() < 0.9 { Vector3::x() } else { Vector3::y() };
+        let u = (reference - axis.into_inner() * axis.dot(&reference)).normalize();
+        let v = axis.cross(&u);
+
+        let (sin_elevation, cos_elevation) = self.elevation.to_radians().sin_cos();
+        let target = Vector3::from(self.target);
+
+        Ok((0..self.frames)
+            .map(|frame| {
+                let angle = (frame as f32 / self.frames as f32).mul_add(TAU, self.start_angle.to_radians());
+                let (sin, cos) = angle.sin_cos();
+
+                let direction = (u * cos + v * sin) * cos_elevation + axis.into_inner() * sin_elevation;
+                let position = target + direction * self.radius;
+
+                Vec3::new(position.x, position.y, position.z)
+            })
+            .collect())
+    }
+
+    pub fn sequence(&self) -> Sequence {
+        Sequence {
+            animation: self.animation.as_ref().map(|animation| SequenceAnimation {
+                fps: animation.fps,
+                plays: animation.plays,
+            }),
+            contact_sheet: self.contact_sheet.as_ref().map(|sheet| ContactSheet {
+                columns: sheet
+                    .columns
+                    .unwrap_or_else(|| (self.frames as f32).sqrt().ceil() as usize),
+            }),
+        }
+    }
+}
+
+const fn default_axis() -> Vec3 {
+    Vec3::new(0.0, 0.0, 1.0)
+}
+
+const fn default_fps() -> u16 {
+    12
+}
@@ -0,0 +1,101 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use antler_id::ObjectId;
+use antler_image::RgbImage;
+use antler_scene::{Resources, Scatter, Scene};
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ConfigError, vec2::Vec2};
+
+// Scatters instances of a named object over the surface of another, sharing the instanced object's resources.
+// Each placement is applied on top of the object's own transform, so a scale or an up-axis correction given to the
+// template carries over to every copy, while any translation offsets them from the surface along its normal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScatterConfig {
+    pub object: String,
+    pub surface: String,
+    pub count: usize,
+    #[serde(default)]
+    pub spacing: f32,
+    #[serde(default = "default_align")]
+    pub align: bool,
+    // Spin about the surface normal, in degrees.
+    #[serde(default = "default_rotation")]
+    pub rotation: Vec2,
+    #[serde(default = "default_scale")]
+    pub scale: Vec2,
+    #[serde(default)]
+    pub density: Option<PathBuf>,
+    #[serde(default)]
+    pub seed: u64,
+}
+
+impl ScatterConfig {
+    pub fn build(
+        self,
+        scene: &mut Scene,
+        resources: &Resources,
+        object_names: &BTreeMap<String, ObjectId>,
+    ) -> Result<(), ConfigError> {
+        let [object_id, surface_id] = [&self.object, &self.surface].map(|name| {
+            object_names
+                .get(name)
+                .copied()
+                .ok_or_else(|| ConfigError::ParseError(format!("Scatter refers to unknown object '{name}'")))
+        });
+        let (object_id, surface_id) = (object_id?, surface_id?);
+
+        let [min_scale, max_scale] = self.scale.0;
+        let invalid = if !(self.spacing.is_finite() && self.spacing >= 0.0) {
+            Some(format!("spacing must be non-negative, got {}", self.spacing))
+        } else if !(self.rotation.0.iter().all(|angle| angle.is_finite()) && self.rotation.0[0] <= self.rotation.0[1]) {
+            Some(format!("rotation range must be ordered, got {:?}", self.rotation.0))
+        } else if !(min_scale > 0.0 && min_scale <= max_scale && max_scale.is_finite()) {
+            Some(format!(
+                "scale range must be positive and ordered, got {:?}",
+                self.scale.0
+            ))
+        } else {
+            None
+        };
+        if let Some(reason) = invalid {
+            return Err(ConfigError::ParseError(format!(
+                "Scatter of '{}' over '{}': {reason}",
+                self.object, self.surface
+            )));
+        }
+
+        let density = self.density.map(RgbImage::load).transpose()?;
+        let [min_rotation, max_rotation] = self.rotation.0.map(f32::to_radians);
+
+        let scatter = Scatter::new(self.count, self.seed)
+            .with_spacing(self.spacing)
+            .with_align(self.align)
+            .with_rotation([min_rotation, max_rotation])
+            .with_scale(self.scale.0)
+            .with_density(density);
+
+        let surface = scene.get_object(surface_id);
+        let transforms = scatter.transforms(resources.get_geometry(surface.geometry_id), &surface.transform);
+
+        for transform in transforms {
+            let instance = scene.get_object(object_id).instance(transform);
+            scene.add_object(instance);
+        }
+
+        Ok(())
+    }
+}
+
+const fn default_align() -> bool {
+    true
+}
+
+const fn default_rotation() -> Vec2 {
+    Vec2::new(0.0, 360.0)
+}
+
+const fn default_scale() -> Vec2 {
+    Vec2::new(1.0, 1.0)
+}
@@ -1,13 +1,24 @@
 use std::collections::BTreeMap;
 
 use antler_colour::Rgb;
+use antler_id::ObjectId;
 use antler_parameters::SceneParameters;
 use antler_scene::{Resources, Scene};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    capture_config::CaptureConfig, errors::ConfigError, light_config::LightConfig, object_config::ObjectConfig,
-    occlusion_config::OcclusionConfig, skybox_config::SkyboxConfig,
+    animation::SceneAnimation,
+    animation_config::AnimationConfig,
+    capture_config::CaptureConfig,
+    errors::ConfigError,
+    gltf_config::{GltfConfig, GltfImport},
+    instances_config::InstancesConfig,
+    light_config::LightConfig,
+    obj_config::ObjConfig,
+    object_config::ObjectConfig,
+    occlusion_config::OcclusionConfig,
+    scatter_config::ScatterConfig,
+    skybox_config::SkyboxConfig,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,11 +29,31 @@ pub struct SceneConfig {
     pub occlusion: Option<OcclusionConfig>,
     pub lights: Vec<LightConfig>,
     pub objects: Vec<ObjectConfig>,
+    #[serde(default)]
+    pub gltf: Vec<GltfConfig>,
+    #[serde(default)]
+    pub obj: Vec<ObjConfig>,
+    #[serde(default)]
+    pub instances: Vec<InstancesConfig>,
+    #[serde(default)]
+    pub scatter: Vec<ScatterConfig>,
     pub captures: BTreeMap<String, CaptureConfig>,
+    #[serde(default)]
+    pub animation: AnimationConfig,
 }
 
 impl SceneConfig {
-    pub fn build(self, resources: &mut Resources) -> Result<SceneParameters, ConfigError> {
+    pub fn build(mut self, resources: &mut Resources) -> Result<(SceneParameters, SceneAnimation), ConfigError> {
+        let imports = self.gltf.iter().map(GltfConfig::load).collect::<Result<Vec<_>, _>>()?;
+
+        let imported_cameras: BTreeMap<_, _> = imports.iter().flat_map(GltfImport::cameras).collect();
+        for capture in self.captures.values_mut() {
+            capture.camera.resolve(&imported_cameras)?;
+        }
+
+        let object_names = self.object_names()?;
+        let animation = SceneAnimation::new(&self, object_names.clone())?;
+
         let mut scene = Scene::new();
 
         scene.set_ambient(self.ambient);
@@ -33,7 +64,8 @@ impl SceneConfig {
             scene.set_occlusion(Some(occlusion.build()));
         }
 
-        for light in self.lights {
+        let imported_lights = imports.iter().flat_map(GltfImport::lights);
+        for light in self.lights.into_iter().chain(imported_lights) {
             scene.add_light(light.build());
         }
 
@@ -41,15 +73,44 @@ impl SceneConfig {
             scene.add_object(object.build(resources)?);
         }
 
+        for import in &imports {
+            import.build(&mut scene, resources)?;
+        }
+
+        for obj in self.obj {
+            obj.build(&mut scene, resources)?;
+        }
+
+        for instances in self.instances {
+            instances.build(&mut scene, &object_names)?;
+        }
+
+        for scatter in self.scatter {
+            scatter.build(&mut scene, resources, &object_names)?;
+        }
+
         scene.build(resources);
 
-        Ok(SceneParameters {
-            scene,
-            captures: self
-                .captures
-                .into_iter()
-                .map(|(name, capture)| (name, capture.build()))
-                .collect(),
-        })
+        let captures = self
+            .captures
+            .into_iter()
+            .map(|(name, capture)| Ok((name, capture.build(&scene, resources, &object_names)?)))
+            .collect::<Result<_, ConfigError>>()?;
+
+        Ok((SceneParameters { scene, captures }, animation))
+    }
+
+    pub fn object_names(&self) -> Result<BTreeMap<String, ObjectId>, ConfigError> {
+        let mut object_names = BTreeMap::new();
+
+        for (index, object) in self.objects.iter().enumerate() {
+            if let Some(name) = &object.name
+                && object_names.insert(name.clone(), ObjectId::new(index)).is_some()
+            {
+                return Err(ConfigError::ParseError(format!("Duplicate object name '{name}'")));
+            }
+        }
+
+        Ok(object_names)
     }
 }
@@ -0,0 +1,197 @@
+use antler_geometry::{SdfNode, SdfPrimitive};
+use serde::{Deserialize, Serialize};
+
+use crate::vec3::Vec3;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum SdfConfig {
+    Sphere {
+        #[serde(default = "default_centre")]
+        centre: Vec3,
+        #[serde(default = "default_radius")]
+        radius: f32,
+    },
+    Box {
+        #[serde(default = "default_centre")]
+        centre: Vec3,
+        #[serde(default = "default_half_size")]
+        half_size: Vec3,
+    },
+    RoundedBox {
+        #[serde(default = "default_centre")]
+        centre: Vec3,
+        #[serde(default = "default_half_size")]
+        half_size: Vec3,
+        #[serde(default = "default_rounding")]
+        radius: f32,
+    },
+    Torus {
+        #[serde(default = "default_centre")]
+        centre: Vec3,
+        #[serde(default = "default_radius")]
+        major_radius: f32,
+        #[serde(default = "default_minor_radius")]
+        minor_radius: f32,
+    },
+    Cylinder {
+        #[serde(default = "default_start_position")]
+        a: Vec3,
+        #[serde(default = "default_end_position")]
+        b: Vec3,
+        #[serde(default = "default_radius")]
+        radius: f32,
+    },
+    Plane {
+        #[serde(default = "default_normal")]
+        normal: Vec3,
+        #[serde(default)]
+        offset: f32,
+    },
+    Union(Vec<Self>),
+    Intersection(Vec<Self>),
+    Difference(Box<Self>, Box<Self>),
+    SmoothUnion {
+        blend: f32,
+        children: Vec<Self>,
+    },
+    SmoothIntersection {
+        blend: f32,
+        children: Vec<Self>,
+    },
+    SmoothDifference {
+        blend: f32,
+        a: Box<Self>,
+        b: Box<Self>,
+    },
+    Repeat {
+        spacing: Vec3,
+        limit: [u32; 3],
+        child: Box<Self>,
+    },
+    Twist {
+        rate: f32,
+        child: Box<Self>,
+    },
+    Displace {
+        amplitude: f32,
+        frequency: f32,
+        child: Box<Self>,
+    },
+}
+
+impl SdfConfig {
+    pub fn build(self) -> SdfNode {
+        let build_all = |children: Vec<Self>| children.into_iter().map(Self::build).collect();
+
+        match self {
+            Self::Sphere { centre, radius } => SdfPrimitive::Sphere {
+                centre: centre.into(),
+                radius,
+            }
+            .into(),
+            Self::Box { centre, half_size } => SdfPrimitive::Box {
+                centre: centre.into(),
+                half_size: half_size.into(),
+            }
+            .into(),
+            Self::RoundedBox {
+                centre,
+                half_size,
+                radius,
+            } => SdfPrimitive::RoundedBox {
+                centre: centre.into(),
+                half_size: half_size.into(),
+                radius,
+            }
+            .into(),
+            Self::Torus {
+                centre,
+                major_radius,
+                minor_radius,
+            } => SdfPrimitive::Torus {
+                centre: centre.into(),
+                major_radius,
+                minor_radius,
+            }
+            .into(),
+            Self::Cylinder { a, b, radius } => SdfPrimitive::Cylinder {
+                a: a.into(),
+                b: b.into(),
+                radius,
+            }
+            .into(),
+            Self::Plane { normal, offset } => SdfPrimitive::Plane {
+                normal: normal.into(),
+                offset,
+            }
+            .into(),
+            Self::Union(children) => SdfNode::Union(build_all(children)),
+            Self::Intersection(children) => SdfNode::Intersection(build_all(children)),
+            Self::Difference(a, b) => SdfNode::Difference(Box::new(a.build()), Box::new(b.build())),
+            Self::SmoothUnion { blend, children } => SdfNode::SmoothUnion {
+                blend,
+                children: build_all(children),
+            },
+            Self::SmoothIntersection { blend, children } => SdfNode::SmoothIntersection {
+                blend,
+                children: build_all(children),
+            },
+            Self::SmoothDifference { blend, a, b } => SdfNode::SmoothDifference {
+                blend,
+                a: Box::new(a.build()),
+                b: Box::new(b.build()),
+            },
+            Self::Repeat { spacing, limit, child } => SdfNode::Repeat {
+                spacing: spacing.into(),
+                limit: limit.map(|count| count as f32).into(),
+                child: Box::new(child.build()),
+            },
+            Self::Twist { rate, child } => SdfNode::Twist {
+                rate: rate.to_radians(),
+                child: Box::new(child.build()),
+            },
+            Self::Displace {
+                amplitude,
+                frequency,
+                child,
+            } => SdfNode::Displace {
+                amplitude,
+                frequency,
+                child: Box::new(child.build()),
+            },
+        }
+    }
+}
+
+const fn default_centre() -> Vec3 {
+    Vec3::new(0.0, 0.0, 0.0)
+}
+
+const fn default_half_size() -> Vec3 {
+    Vec3::new(0.5, 0.5, 0.5)
+}
+
+const fn default_start_position() -> Vec3 {
+    Vec3::new(0.0, 0.0, 1.0)
+}
+
+const fn default_end_position() -> Vec3 {
+    Vec3::new(0.0, 0.0, -1.0)
+}
+
+const fn default_normal() -> Vec3 {
+    Vec3::new(0.0, 0.0, 1.0)
+}
+
+const fn default_radius() -> f32 {
+    1.0
+}
+
+const fn default_minor_radius() -> f32 {
+    0.25
+}
+
+const fn default_rounding() -> f32 {
+    0.1
+}
@@ -3,13 +3,14 @@ use std::path::PathBuf;
 use antler_colour::Rgb;
 use antler_image::RgbImage;
 use antler_shader::{
-    Angular, Block, Checkerboard, Gradient, Iridescent, Luminous, Normal, Shader, Solid, Textured, Wireframe,
+    Angular, Block, Checkerboard, Gradient, Iridescent, Luminous, Normal, Shader, Solid, Textured, VertexColour,
+    Wireframe,
 };
 use serde::{Deserialize, Serialize};
 
 use crate::{errors::ConfigError, gradient_config::GradientConfig, vec3::Vec3};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub enum ShaderConfig {
     Angular {
@@ -45,6 +46,11 @@ pub enum ShaderConfig {
     Textured {
         path: PathBuf,
     },
+    // Colours from mesh vertices, with `colour` used on surfaces that have none.
+    VertexColour {
+        #[serde(default = "default_vertex_colour")]
+        colour: Rgb,
+    },
     Wireframe {
         surface_colour: Rgb,
         line_colour: Rgb,
@@ -53,6 +59,48 @@ pub enum ShaderConfig {
 }
 
 impl ShaderConfig {
+    pub const fn set_colour(&mut self, value: Rgb) -> bool {
+        match self {
+            Self::Block { colour }
+            | Self::Luminous { colour, .. }
+            | Self::Solid { colour }
+            | Self::VertexColour { colour }
+            | Self::Checkerboard { colour_a: colour, .. }
+            | Self::Wireframe {
+                surface_colour: colour, ..
+            } => {
+                *colour = value;
+                true
+            }
+            Self::Angular { .. }
+            | Self::Gradient { .. }
+            | Self::Iridescent { .. }
+            | Self::Normal
+            | Self::Textured { .. } => false,
+        }
+    }
+
+    pub const fn set_secondary_colour(&mut self, value: Rgb) -> bool {
+        match self {
+            Self::Checkerboard { colour_b: colour, .. }
+            | Self::Wireframe {
+                line_colour: colour, ..
+            } => {
+                *colour = value;
+                true
+            }
+            Self::Angular { .. }
+            | Self::Block { .. }
+            | Self::Gradient { .. }
+            | Self::Iridescent { .. }
+            | Self::Luminous { .. }
+            | Self::Normal
+            | Self::Solid { .. }
+            | Self::Textured { .. }
+            | Self::VertexColour { .. } => false,
+        }
+    }
+
     pub fn build(self) -> Result<Shader, ConfigError> {
         Ok(match self {
             Self::Angular {
@@ -72,6 +120,7 @@ impl ShaderConfig {
             Self::Normal => Normal::new().into(),
             Self::Solid { colour } => Solid::new(colour).into(),
             Self::Textured { path } => Textured::new(RgbImage::load(path)?).into(),
+            Self::VertexColour { colour } => VertexColour::new(colour).into(),
             Self::Wireframe {
                 surface_colour,
                 line_colour,
@@ -81,6 +130,10 @@ impl ShaderConfig {
     }
 }
 
+const fn default_vertex_colour() -> Rgb {
+    Rgb::WHITE
+}
+
 const fn default_direction() -> Vec3 {
     Vec3::new(0.0, 0.0, 1.0)
 }
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{camera_config::CameraConfig, vec3::Vec3};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShutterConfig {
+    #[serde(default)]
+    pub open: f32,
+    #[serde(default = "default_close")]
+    pub close: f32,
+    // Where the camera is when the shutter closes, for camera motion blur.
+    #[serde(default)]
+    pub position: Option<Vec3>,
+    #[serde(default)]
+    pub look_at: Option<Vec3>,
+}
+
+impl ShutterConfig {
+    #[must_use]
+    pub fn end_camera(&self, camera: &CameraConfig) -> Option<CameraConfig> {
+        if self.position.is_none() && self.look_at.is_none() {
+            return None;
+        }
+
+        let (position, look_at) = camera.view();
+        let mut end_camera = camera.clone();
+        end_camera.aim(self.position.unwrap_or(position), self.look_at.unwrap_or(look_at));
+
+        Some(end_camera)
+    }
+}
+
+const fn default_close() -> f32 {
+    1.0
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimelineConfig {
+    #[serde(default)]
+    pub start: usize,
+    pub end: usize,
+    #[serde(default = "default_fps")]
+    pub fps: f32,
+}
+
+impl TimelineConfig {
+    #[must_use]
+    #[inline]
+    pub fn time(&self, frame: usize) -> f32 {
+        frame as f32 / self.fps
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn frame_duration(&self) -> f32 {
+        self.fps.recip()
+    }
+}
+
+const fn default_fps() -> f32 {
+    24.0
+}
@@ -0,0 +1,123 @@
+use antler_colour::Rgb;
+use serde::{Deserialize, Serialize};
+
+use crate::{errors::ConfigError, transform::Transform, vec3::Vec3};
+
+const BEZIER_ITERATIONS: usize = 16;
+
+pub trait Interpolate {
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum InterpolationConfig {
+    #[default]
+    Linear,
+    Smoothstep,
+    // Control points of a CSS-style timing curve from (0, 0) to (1, 1).
+    Bezier(f32, f32, f32, f32),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Keyframe<T> {
+    pub time: f32,
+    pub value: T,
+    #[serde(default)]
+    pub interpolation: InterpolationConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Track<T>(pub Vec<Keyframe<T>>);
+
+impl<T: Interpolate + Clone> Track<T> {
+    pub fn validate(&self, name: &str) -> Result<(), ConfigError> {
+        if self.0.is_empty() {
+            return Err(ConfigError::Animation(format!("Track '{name}' has no keyframes")));
+        }
+
+        if self.0.windows(2).any(|pair| pair[0].time >= pair[1].time) {
+            return Err(ConfigError::Animation(format!(
+                "Keyframes of track '{name}' must be in strictly increasing time order"
+            )));
+        }
+
+        Ok(())
+    }
+
+    // Holds the first and last values outside the keyed range.
+    pub fn sample(&self, time: f32) -> T {
+        let keyframes = &self.0;
+        let next = keyframes.partition_point(|keyframe| keyframe.time <= time);
+
+        if next == 0 {
+            return keyframes[0].value.clone();
+        }
+        if next == keyframes.len() {
+            return keyframes[next - 1].value.clone();
+        }
+
+        let (from, to) = (&keyframes[next - 1], &keyframes[next]);
+        let t = (time - from.time) / (to.time - from.time);
+
+        from.value.interpolate(&to.value, from.interpolation.ease(t))
+    }
+}
+
+impl InterpolationConfig {
+    fn ease(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::Smoothstep => t * t * 2.0f32.mul_add(-t, 3.0),
+            Self::Bezier(x1, y1, x2, y2) => {
+                // The curve's x is monotonic for control x within [0, 1], so bisection finds the parameter for t.
+                let (mut low, mut high) = (0.0, 1.0);
+                for _ in 0..BEZIER_ITERATIONS {
+                    let mid = (low + high) * 0.5;
+                    if cubic_bezier(x1, x2, mid) < t {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+
+                cubic_bezier(y1, y2, (low + high) * 0.5)
+            }
+        }
+    }
+}
+
+fn cubic_bezier(p1: f32, p2: f32, s: f32) -> f32 {
+    let inv = 1.0 - s;
+    (3.0 * inv * s).mul_add(inv.mul_add(p1, s * p2), s * s * s)
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        (other - self).mul_add(t, *self)
+    }
+}
+
+impl Interpolate for Vec3 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self([0, 1, 2].map(|axis| self.0[axis].interpolate(&other.0[axis], t)))
+    }
+}
+
+impl Interpolate for Rgb {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        *self * (1.0 - t) + *other * t
+    }
+}
+
+// Euler angles are interpolated directly, so keyframes can describe more than a half turn between them.
+impl Interpolate for Transform {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            translation: self.translation.interpolate(&other.translation, t),
+            rotation: self.rotation.interpolate(&other.rotation, t),
+            scale: self.scale.interpolate(&other.scale, t),
+        }
+    }
+}
@@ -93,3 +93,13 @@ fn expand_includes_inner(
 fn is_line_comment_at(input: &str, i: usize) -> bool {
     input[i..].starts_with("//")
 }
+
+#[must_use]
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01B3;
+
+    bytes
+        .iter()
+        .fold(OFFSET_BASIS, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(PRIME))
+}
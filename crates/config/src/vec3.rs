@@ -16,6 +16,18 @@ impl From<Vec3> for Point3<f32> {
     }
 }
 
+impl From<Point3<f32>> for Vec3 {
+    fn from(value: Point3<f32>) -> Self {
+        Self::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<Vector3<f32>> for Vec3 {
+    fn from(value: Vector3<f32>) -> Self {
+        Self::new(value.x, value.y, value.z)
+    }
+}
+
 impl From<Vec3> for Vector3<f32> {
     fn from(value: Vec3) -> Self {
         Self::new(value.0[0], value.0[1], value.0[2])
@@ -2,8 +2,8 @@ use nalgebra::{Point2, Point3, Similarity3, Unit, Vector3};
 use rand::{Rng, RngExt};
 
 use crate::{
-    bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, ray::Ray, sample::Sample, sampleable::Sampleable,
-    traceable::Traceable,
+    bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, crossings::convex_crossings, ray::Ray, sample::Sample,
+    sampleable::Sampleable, traceable::Traceable,
 };
 
 const PARALLEL_THRESHOLD: f32 = 1e-8;
@@ -88,6 +88,12 @@ impl Aabb {
         ]
     }
 
+    #[must_use]
+    #[inline]
+    pub(crate) fn ray_interval(&self, ray: &Ray) -> Option<(f32, f32)> {
+        self.ray_intersection(ray).map(|(t_min, t_max, _, _)| (t_min, t_max))
+    }
+
     #[must_use]
     fn ray_intersection(&self, ray: &Ray) -> Option<(f32, f32, AabbFace, AabbFace)> {
         let origin = ray.origin;
@@ -188,6 +194,11 @@ impl Traceable for Aabb {
 
         Some(Contact::new(distance, position, normal, uv, None))
     }
+
+    #[inline]
+    fn crossings(&self, ray: &Ray) -> Option<Vec<Contact>> {
+        Some(convex_crossings(self, ray))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,128 @@
+use std::f32::consts::{PI, TAU};
+
+use nalgebra::{Point2, Point3, Unit, Vector3};
+use rand::{Rng, RngExt};
+
+use crate::{
+    aabb::Aabb, bounded::Bounded, contact::Contact, plane::Plane, ray::Ray, sample::Sample, sampleable::Sampleable,
+    traceable::Traceable,
+};
+
+pub struct Annulus {
+    plane: Plane,
+    inner_radius: f32,
+    outer_radius: f32,
+}
+
+impl Annulus {
+    #[must_use]
+    pub fn new(centre: Point3<f32>, normal: Unit<Vector3<f32>>, inner_radius: f32, outer_radius: f32) -> Self {
+        assert!(inner_radius >= 0.0, "Annulus inner radius must be non-negative");
+        assert!(
+            outer_radius > inner_radius,
+            "Annulus outer radius must exceed its inner radius"
+        );
+
+        Self {
+            plane: Plane::new(centre, normal),
+            inner_radius,
+            outer_radius,
+        }
+    }
+
+    #[inline]
+    fn local_hit(&self, ray: &Ray, max_distance: f32) -> Option<(f32, Point2<f32>)> {
+        let distance = self.plane.ray_distance(ray)?;
+
+        if distance >= max_distance {
+            return None;
+        }
+
+        let position = ray.origin + *ray.direction * distance;
+        let local = self.plane.project(position);
+        let radius_squared = local.coords.norm_squared();
+
+        (radius_squared >= self.inner_radius * self.inner_radius
+            && radius_squared <= self.outer_radius * self.outer_radius)
+            .then_some((distance, local))
+    }
+}
+
+impl Bounded for Annulus {
+    fn bounds(&self) -> Aabb {
+        let n = self.plane.normal.into_inner().abs();
+
+        let extent = Vector3::new(
+            self.outer_radius * n.x.mul_add(-n.x, 1.0).sqrt(),
+            self.outer_radius * n.y.mul_add(-n.y, 1.0).sqrt(),
+            self.outer_radius * n.z.mul_add(-n.z, 1.0).sqrt(),
+        );
+
+        Aabb::new(self.plane.position - extent, self.plane.position + extent)
+    }
+}
+
+impl Traceable for Annulus {
+    #[inline]
+    fn hit(&self, ray: &Ray, max_distance: f32) -> bool {
+        self.local_hit(ray, max_distance).is_some()
+    }
+
+    #[inline]
+    fn distance(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
+        self.local_hit(ray, max_distance).map(|(distance, _)| distance)
+    }
+
+    #[inline]
+    fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact> {
+        let (distance, local) = self.local_hit(ray, max_distance)?;
+        let position = ray.origin + *ray.direction * distance;
+
+        // Polar coordinates run around the ring and outward across its width.
+        let uv = Point2::new(
+            (local.y.atan2(local.x) + PI) / TAU,
+            (local.coords.norm() - self.inner_radius) / (self.outer_radius - self.inner_radius),
+        );
+
+        Some(Contact::new(
+            distance,
+            position,
+            self.plane.normal_for_ray(ray),
+            uv,
+            None,
+        ))
+    }
+}
+
+impl Sampleable for Annulus {
+    #[inline]
+    fn area(&self) -> f32 {
+        PI * self
+            .outer_radius
+            .mul_add(self.outer_radius, -(self.inner_radius * self.inner_radius))
+    }
+
+    #[inline]
+    fn sample<R: Rng>(&self, rng: &mut R) -> Sample {
+        let inner_squared = self.inner_radius * self.inner_radius;
+        let r = rng
+            .random::<f32>()
+            .mul_add(
+                self.outer_radius.mul_add(self.outer_radius, -inner_squared),
+                inner_squared,
+            )
+            .sqrt();
+        let theta = TAU * rng.random::<f32>();
+
+        let local_x = r * theta.cos();
+        let local_y = r * theta.sin();
+
+        let position = self.plane.position + *self.plane.tangent * local_x + *self.plane.bi_tangent * local_y;
+
+        Sample {
+            position,
+            normal: self.plane.normal,
+            pdf_area: 1.0 / self.area(),
+        }
+    }
+}
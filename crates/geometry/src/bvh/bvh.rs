@@ -32,6 +32,12 @@ impl<T: Copy> Bvh<T> {
         Self { nodes, primitive_ids }
     }
 
+    #[must_use]
+    #[inline]
+    pub fn bounds(&self) -> Aabb {
+        self.nodes[0].aabb
+    }
+
     pub fn nearest_with_max<F>(&self, ray: &Ray, best_distance: &mut f32, mut visit: F)
     where
         F: FnMut(T, &mut f32) -> bool,
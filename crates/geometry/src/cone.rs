@@ -0,0 +1,261 @@
+use std::f32::consts::{PI, TAU};
+
+use nalgebra::{Point2, Point3, Unit, Vector3};
+use rand::{Rng, RngExt};
+
+use crate::{
+    aabb::Aabb, bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, crossings::convex_crossings,
+    plane::Plane, ray::Ray, sample::Sample, sampleable::Sampleable, traceable::Traceable,
+};
+
+const PARALLEL_THRESHOLD: f32 = 1e-8;
+
+#[derive(Debug, Clone, Copy)]
+enum Surface {
+    Side,
+    StartCap,
+    EndCap,
+}
+
+// A truncated cone between two end points, which is a full cone when either radius is zero.
+pub struct Cone {
+    base: Plane,
+    height: f32,
+    radius_a: f32,
+    radius_b: f32,
+    caps: bool,
+}
+
+impl Cone {
+    #[must_use]
+    pub fn new(a: Point3<f32>, b: Point3<f32>, radius_a: f32, radius_b: f32, caps: bool) -> Self {
+        assert!((b - a).norm_squared() > 1.0e-12, "Cone endpoints must differ");
+        assert!(radius_a >= 0.0 && radius_b >= 0.0, "Cone radii must be non-negative");
+        assert!(
+            radius_a > 0.0 || radius_b > 0.0,
+            "Cone must have at least one positive radius"
+        );
+
+        Self {
+            base: Plane::new(a, Unit::new_normalize(b - a)),
+            height: (b - a).norm(),
+            radius_a,
+            radius_b,
+            caps,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_capped(&self) -> bool {
+        self.caps
+    }
+
+    #[inline]
+    fn slope(&self) -> f32 {
+        (self.radius_b - self.radius_a) / self.height
+    }
+
+    #[inline]
+    fn to_local(&self, vector: Vector3<f32>) -> Vector3<f32> {
+        Vector3::new(
+            vector.dot(&self.base.tangent),
+            vector.dot(&self.base.bi_tangent),
+            vector.dot(&self.base.normal),
+        )
+    }
+
+    #[inline]
+    fn to_world(&self, local: Vector3<f32>) -> Vector3<f32> {
+        *self.base.tangent * local.x + *self.base.bi_tangent * local.y + *self.base.normal * local.z
+    }
+
+    fn local_hit(&self, ray: &Ray, max_distance: f32) -> Option<(f32, Surface)> {
+        let origin = self.to_local(ray.origin - self.base.position);
+        let direction = self.to_local(ray.direction.into_inner());
+        let slope = self.slope();
+
+        let mut closest = None;
+        let mut consider = |distance: f32, surface: Surface| {
+            if distance > MIN_RAY_DISTANCE && distance < max_distance && closest.is_none_or(|(best, _)| distance < best)
+            {
+                closest = Some((distance, surface));
+            }
+        };
+
+        // The side satisfies x^2 + y^2 = r(z)^2 with r linear in z, giving a quadratic in the ray distance.
+        let radius_origin = slope.mul_add(origin.z, self.radius_a);
+        let a = (slope * direction.z).mul_add(-(slope * direction.z), direction.xy().norm_squared());
+        let half_b = (radius_origin * slope).mul_add(-direction.z, origin.xy().dot(&direction.xy()));
+        let c = radius_origin.mul_add(-radius_origin, origin.xy().norm_squared());
+
+        if a.abs() > PARALLEL_THRESHOLD {
+            let discriminant = half_b.mul_add(half_b, -(a * c));
+
+            if discriminant >= 0.0 {
+                let sqrt_d = discriminant.sqrt();
+
+                for distance in [(-half_b - sqrt_d) / a, (-half_b + sqrt_d) / a] {
+                    let z = direction.z.mul_add(distance, origin.z);
+                    if (0.0..=self.height).contains(&z) {
+                        consider(distance, Surface::Side);
+                    }
+                }
+            }
+        }
+
+        if self.caps && direction.z.abs() > PARALLEL_THRESHOLD {
+            for (z, radius, surface) in [
+                (0.0, self.radius_a, Surface::StartCap),
+                (self.height, self.radius_b, Surface::EndCap),
+            ] {
+                let distance = (z - origin.z) / direction.z;
+                let position = origin + direction * distance;
+
+                if radius > 0.0 && position.xy().norm_squared() <= radius * radius {
+                    consider(distance, surface);
+                }
+            }
+        }
+
+        closest
+    }
+
+    fn local_normal(&self, local: Vector3<f32>, surface: Surface) -> Vector3<f32> {
+        match surface {
+            Surface::Side => {
+                let radius = self.slope().mul_add(local.z, self.radius_a);
+                Vector3::new(local.x, local.y, -self.slope() * radius)
+            }
+            Surface::StartCap => -Vector3::z(),
+            Surface::EndCap => Vector3::z(),
+        }
+    }
+
+    fn uv(&self, local: Vector3<f32>, surface: Surface) -> Point2<f32> {
+        match surface {
+            Surface::Side => Point2::new((local.y.atan2(local.x) + PI) / TAU, local.z / self.height),
+            Surface::StartCap => Point2::new(
+                0.5 + local.x / (2.0 * self.radius_a),
+                0.5 + local.y / (2.0 * self.radius_a),
+            ),
+            Surface::EndCap => Point2::new(
+                0.5 + local.x / (2.0 * self.radius_b),
+                0.5 + local.y / (2.0 * self.radius_b),
+            ),
+        }
+    }
+
+    fn side_area(&self) -> f32 {
+        let slant = self.height.hypot(self.radius_b - self.radius_a);
+        PI * (self.radius_a + self.radius_b) * slant
+    }
+
+    fn cap_areas(&self) -> [f32; 2] {
+        if self.caps {
+            [self.radius_a, self.radius_b].map(|radius| PI * radius * radius)
+        } else {
+            [0.0, 0.0]
+        }
+    }
+}
+
+impl Bounded for Cone {
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        let n = self.base.normal.into_inner().abs();
+        let spread = Vector3::new(
+            n.x.mul_add(-n.x, 1.0).sqrt(),
+            n.y.mul_add(-n.y, 1.0).sqrt(),
+            n.z.mul_add(-n.z, 1.0).sqrt(),
+        );
+
+        let end = self.base.position + *self.base.normal * self.height;
+
+        Aabb::union(
+            [(self.base.position, self.radius_a), (end, self.radius_b)]
+                .into_iter()
+                .map(|(centre, radius)| Aabb::new(centre - spread * radius, centre + spread * radius)),
+        )
+    }
+}
+
+impl Traceable for Cone {
+    #[inline]
+    fn hit(&self, ray: &Ray, max_distance: f32) -> bool {
+        self.local_hit(ray, max_distance).is_some()
+    }
+
+    #[inline]
+    fn distance(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
+        self.local_hit(ray, max_distance).map(|(distance, _)| distance)
+    }
+
+    #[inline]
+    fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact> {
+        let (distance, surface) = self.local_hit(ray, max_distance)?;
+
+        let position = ray.origin + *ray.direction * distance;
+        let local = self.to_local(position - self.base.position);
+
+        let mut normal = Unit::new_normalize(self.to_world(self.local_normal(local, surface)));
+        if normal.dot(&ray.direction) > 0.0 {
+            normal = -normal;
+        }
+
+        Some(Contact::new(distance, position, normal, self.uv(local, surface), None))
+    }
+
+    // Only a capped cone is a closed, convex solid.
+    #[inline]
+    fn crossings(&self, ray: &Ray) -> Option<Vec<Contact>> {
+        self.is_capped().then(|| convex_crossings(self, ray))
+    }
+}
+
+impl Sampleable for Cone {
+    #[inline]
+    fn area(&self) -> f32 {
+        let [start_cap, end_cap] = self.cap_areas();
+        self.side_area() + start_cap + end_cap
+    }
+
+    #[inline]
+    fn sample<R: Rng>(&self, rng: &mut R) -> Sample {
+        let side_area = self.side_area();
+        let [start_cap, _] = self.cap_areas();
+        let total_area = self.area();
+
+        let pick = rng.random::<f32>() * total_area;
+        let theta = TAU * rng.random::<f32>();
+        let (sin, cos) = theta.sin_cos();
+
+        let (local, surface) = if pick < side_area {
+            // Circumference grows linearly along the axis, so the radius is drawn by inverting its quadratic CDF.
+            let (ra, rb) = (self.radius_a, self.radius_b);
+            let radius = rng.random::<f32>().mul_add(rb.mul_add(rb, -(ra * ra)), ra * ra).sqrt();
+            let z = if (rb - ra).abs() > PARALLEL_THRESHOLD {
+                (radius - ra) / self.slope()
+            } else {
+                rng.random::<f32>() * self.height
+            };
+
+            (Vector3::new(radius * cos, radius * sin, z), Surface::Side)
+        } else {
+            let (z, radius, surface) = if pick < side_area + start_cap {
+                (0.0, self.radius_a, Surface::StartCap)
+            } else {
+                (self.height, self.radius_b, Surface::EndCap)
+            };
+            let r = radius * rng.random::<f32>().sqrt();
+
+            (Vector3::new(r * cos, r * sin, z), surface)
+        };
+
+        Sample {
+            position: self.base.position + self.to_world(local),
+            normal: Unit::new_normalize(self.to_world(self.local_normal(local, surface))),
+            pdf_area: 1.0 / total_area,
+        }
+    }
+}
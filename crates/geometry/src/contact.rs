@@ -1,3 +1,4 @@
+use antler_colour::Rgb;
 use nalgebra::{Point2, Point3, Similarity3, Unit, Vector3};
 
 use crate::utils::tangent_frame;
@@ -8,6 +9,7 @@ pub struct Contact {
     pub normal: Unit<Vector3<f32>>,
     pub uv: Point2<f32>,
     pub barycentric: Option<Vector3<f32>>,
+    pub colour: Option<Rgb>,
     tangent: Option<Unit<Vector3<f32>>>,
     bi_tangent: Option<Unit<Vector3<f32>>>,
 }
@@ -28,11 +30,19 @@ impl Contact {
             normal,
             uv,
             barycentric,
+            colour: None,
             tangent: None,
             bi_tangent: None,
         }
     }
 
+    #[must_use]
+    #[inline]
+    pub const fn with_colour(mut self, colour: Option<Rgb>) -> Self {
+        self.colour = colour;
+        self
+    }
+
     #[must_use]
     #[inline]
     pub fn is_interior(&self, direction: &Unit<Vector3<f32>>) -> bool {
@@ -78,6 +88,7 @@ impl Contact {
             normal,
             uv: self.uv,
             barycentric: self.barycentric,
+            colour: self.colour,
             tangent,
             bi_tangent,
         }
@@ -0,0 +1,38 @@
+use crate::{bounded::Bounded, contact::Contact, ray::Ray, traceable::Traceable};
+
+// How far outside the bounds a crossing search starts, so every crossing lies ahead of it.
+const LEAD_IN: f32 = 1.0;
+
+// Places the origin of a ray just before the bounds, returning the new ray and how far along the original it is.
+#[must_use]
+pub fn lead_in<T: Bounded>(shape: &T, ray: &Ray) -> Option<(Ray, f32)> {
+    let (near, _) = shape.bounds().ray_interval(ray)?;
+    let offset = near - LEAD_IN;
+
+    Some((
+        Ray::new(ray.origin + *ray.direction * offset, ray.direction).with_time(ray.time),
+        offset,
+    ))
+}
+
+// A convex solid is crossed at most twice: entering from before its bounds and leaving as seen from beyond them.
+#[must_use]
+pub fn convex_crossings<T: Bounded + Traceable>(shape: &T, ray: &Ray) -> Vec<Contact> {
+    let Some((entry_ray, entry_offset)) = lead_in(shape, ray) else {
+        return Vec::new();
+    };
+    let Some(mut entry) = shape.intersection(&entry_ray, f32::INFINITY) else {
+        return Vec::new();
+    };
+    entry.distance += entry_offset;
+
+    let (_, far) = shape.bounds().ray_interval(ray).expect("the ray crosses the bounds");
+    let exit_offset = far + LEAD_IN;
+    let exit_ray = Ray::new(ray.origin + *ray.direction * exit_offset, -ray.direction).with_time(ray.time);
+    let Some(mut exit) = shape.intersection(&exit_ray, f32::INFINITY) else {
+        return Vec::new();
+    };
+    exit.distance = exit_offset - exit.distance;
+
+    vec![entry, exit]
+}
@@ -0,0 +1,206 @@
+use nalgebra::{Point3, Unit, Vector3};
+use rand::{Rng, RngExt, SeedableRng, rngs::SmallRng};
+
+use crate::{
+    aabb::Aabb, bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, errors::CsgError, geometry::Geometry,
+    ray::Ray, sample::Sample, sampleable::Sampleable, traceable::Traceable,
+};
+
+const AREA_ESTIMATE_SAMPLES: usize = 1024;
+const SAMPLE_ATTEMPTS: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsgOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl CsgOperation {
+    #[must_use]
+    #[inline]
+    pub const fn contains(self, inside_a: bool, inside_b: bool) -> bool {
+        match self {
+            Self::Union => inside_a || inside_b,
+            Self::Intersection => inside_a && inside_b,
+            Self::Difference => inside_a && !inside_b,
+        }
+    }
+}
+
+pub struct Csg {
+    operation: CsgOperation,
+    a: Box<Geometry>,
+    b: Box<Geometry>,
+    bounds: Aabb,
+    area: f32,
+}
+
+impl Csg {
+    pub fn new(operation: CsgOperation, a: Geometry, b: Geometry) -> Result<Self, CsgError> {
+        if !a.is_solid() || !b.is_solid() {
+            return Err(CsgError::OpenChild);
+        }
+
+        let (bounds_a, bounds_b) = (a.bounds(), b.bounds());
+        let bounds = match operation {
+            CsgOperation::Union => Aabb::union([bounds_a, bounds_b].into_iter()),
+            CsgOperation::Intersection => {
+                let min = bounds_a.min.sup(&bounds_b.min);
+                Aabb::new(min, bounds_a.max.inf(&bounds_b.max).sup(&min))
+            }
+            CsgOperation::Difference => bounds_a,
+        };
+
+        let mut csg = Self {
+            operation,
+            a: Box::new(a),
+            b: Box::new(b),
+            bounds,
+            area: 0.0,
+        };
+
+        // The surface is whichever parts of each child's surface survive the operation, so its area is estimated
+        // from the fraction of child samples that do.
+        let mut rng = SmallRng::seed_from_u64(0);
+        let kept = (0..AREA_ESTIMATE_SAMPLES)
+            .filter(|_| csg.sample_child(&mut rng).1)
+            .count();
+        csg.area = (csg.a.area() + csg.b.area()) * kept as f32 / AREA_ESTIMATE_SAMPLES as f32;
+
+        if csg.area <= 0.0 {
+            return Err(CsgError::Empty);
+        }
+
+        Ok(csg)
+    }
+
+    // Draws a point uniformly over both children's surfaces, reporting whether it lies on the combined surface.
+    fn sample_child<R: Rng>(&self, rng: &mut R) -> (Sample, bool) {
+        let area_a = self.a.area();
+        let from_a = rng.random::<f32>() * (area_a + self.b.area()) < area_a;
+
+        let (sample, other) = if from_a {
+            (self.a.sample(rng), &self.b)
+        } else {
+            (self.b.sample(rng), &self.a)
+        };
+
+        let inside_other = contains(other, sample.position);
+        let kept = match (self.operation, from_a) {
+            (CsgOperation::Union, _) | (CsgOperation::Difference, true) => !inside_other,
+            (CsgOperation::Intersection, _) | (CsgOperation::Difference, false) => inside_other,
+        };
+
+        (sample, kept)
+    }
+
+    // Sweeps both children's crossings in order, keeping those where the combined inside/outside state changes.
+    fn combined_crossings(&self, ray: &Ray) -> Vec<Contact> {
+        let crossings_a = self.a.crossings(ray).unwrap_or_default();
+        let crossings_b = self.b.crossings(ray).unwrap_or_default();
+
+        let mut events = crossings_a
+            .into_iter()
+            .map(|contact| (contact, true))
+            .chain(crossings_b.into_iter().map(|contact| (contact, false)))
+            .collect::<Vec<_>>();
+        events.sort_by(|(a, _), (b, _)| a.distance.total_cmp(&b.distance));
+
+        let (mut inside_a, mut inside_b) = (false, false);
+        let mut inside = false;
+
+        events
+            .into_iter()
+            .filter_map(|(contact, from_a)| {
+                if from_a {
+                    inside_a = !inside_a;
+                } else {
+                    inside_b = !inside_b;
+                }
+
+                let now_inside = self.operation.contains(inside_a, inside_b);
+                let changed = now_inside != inside;
+                inside = now_inside;
+
+                changed.then_some(contact)
+            })
+            .collect()
+    }
+}
+
+fn contains(geometry: &Geometry, point: Point3<f32>) -> bool {
+    // An oblique direction makes grazing edges and faces unlikely.
+    let direction = Unit::new_normalize(Vector3::new(1.0, 1.3, 1.7));
+
+    geometry
+        .crossings(&Ray::new(point, direction))
+        .unwrap_or_default()
+        .iter()
+        .filter(|contact| contact.distance > MIN_RAY_DISTANCE)
+        .count()
+        % 2
+        == 1
+}
+
+impl Bounded for Csg {
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        self.bounds
+    }
+}
+
+impl Traceable for Csg {
+    #[inline]
+    fn hit(&self, ray: &Ray, max_distance: f32) -> bool {
+        self.distance(ray, max_distance).is_some()
+    }
+
+    #[inline]
+    fn distance(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
+        self.intersection(ray, max_distance).map(|contact| contact.distance)
+    }
+
+    #[inline]
+    fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact> {
+        let mut contact = self
+            .combined_crossings(ray)
+            .into_iter()
+            .find(|contact| contact.distance > MIN_RAY_DISTANCE)
+            .filter(|contact| contact.distance < max_distance)?;
+
+        if contact.normal.dot(&ray.direction) > 0.0 {
+            contact.normal = -contact.normal;
+        }
+
+        Some(contact)
+    }
+
+    #[inline]
+    fn crossings(&self, ray: &Ray) -> Option<Vec<Contact>> {
+        Some(self.combined_crossings(ray))
+    }
+}
+
+impl Sampleable for Csg {
+    #[inline]
+    fn area(&self) -> f32 {
+        self.area
+    }
+
+    // Construction found the combined surface, so rejection only runs out of attempts if that estimate was wrong.
+    #[inline]
+    fn sample<R: Rng>(&self, rng: &mut R) -> Sample {
+        let candidate = (0..SAMPLE_ATTEMPTS)
+            .map(|_| self.sample_child(rng))
+            .find(|(_, kept)| *kept)
+            .unwrap_or_else(|| {
+                panic!("CSG sampling found no point on the combined surface in {SAMPLE_ATTEMPTS} attempts")
+            });
+
+        Sample {
+            pdf_area: 1.0 / self.area,
+            ..candidate.0
+        }
+    }
+}
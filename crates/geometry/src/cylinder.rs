@@ -0,0 +1,70 @@
+use nalgebra::Point3;
+use rand::Rng;
+
+use crate::{
+    aabb::Aabb, bounded::Bounded, cone::Cone, contact::Contact, ray::Ray, sample::Sample, sampleable::Sampleable,
+    traceable::Traceable,
+};
+
+// A cylinder is the constant-radius case of a truncated cone.
+pub struct Cylinder {
+    cone: Cone,
+}
+
+impl Cylinder {
+    #[must_use]
+    pub fn new(a: Point3<f32>, b: Point3<f32>, radius: f32, caps: bool) -> Self {
+        assert!(radius > 0.0, "Cylinder radius must be positive");
+
+        Self {
+            cone: Cone::new(a, b, radius, radius, caps),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_capped(&self) -> bool {
+        self.cone.is_capped()
+    }
+}
+
+impl Bounded for Cylinder {
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        self.cone.bounds()
+    }
+}
+
+impl Traceable for Cylinder {
+    #[inline]
+    fn hit(&self, ray: &Ray, max_distance: f32) -> bool {
+        self.cone.hit(ray, max_distance)
+    }
+
+    #[inline]
+    fn distance(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
+        self.cone.distance(ray, max_distance)
+    }
+
+    #[inline]
+    fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact> {
+        self.cone.intersection(ray, max_distance)
+    }
+
+    #[inline]
+    fn crossings(&self, ray: &Ray) -> Option<Vec<Contact>> {
+        self.cone.crossings(ray)
+    }
+}
+
+impl Sampleable for Cylinder {
+    #[inline]
+    fn area(&self) -> f32 {
+        self.cone.area()
+    }
+
+    #[inline]
+    fn sample<R: Rng>(&self, rng: &mut R) -> Sample {
+        self.cone.sample(rng)
+    }
+}
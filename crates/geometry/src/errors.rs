@@ -1,20 +1,32 @@
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result},
+    io::Error as IoError,
 };
 
 use tobj::LoadError;
 
 #[derive(Debug)]
 pub enum MeshLoadError {
+    Io(IoError),
     Obj(LoadError),
+    Ply { reason: String },
+    Stl { reason: String },
+    UnsupportedFormat { extension: String },
     EmptyMesh,
+    InconsistentBuffers { reason: String },
     MissingPositionIndex { model_name: String, index: usize },
     MissingNormalIndex { model_name: String, index: usize },
     MissingTexcoordIndex { model_name: String, index: usize },
     InvalidIndexCount { model_name: String, count: usize },
 }
 
+impl From<IoError> for MeshLoadError {
+    fn from(value: IoError) -> Self {
+        Self::Io(value)
+    }
+}
+
 impl From<LoadError> for MeshLoadError {
     fn from(value: LoadError) -> Self {
         Self::Obj(value)
@@ -24,8 +36,15 @@ impl From<LoadError> for MeshLoadError {
 impl Display for MeshLoadError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
+            Self::Io(err) => write!(f, "Failed to read mesh: {err}"),
             Self::Obj(err) => write!(f, "Failed to load OBJ: {err}"),
-            Self::EmptyMesh => write!(f, "OBJ produced no triangles"),
+            Self::Ply { reason } => write!(f, "Failed to load PLY: {reason}"),
+            Self::Stl { reason } => write!(f, "Failed to load STL: {reason}"),
+            Self::UnsupportedFormat { extension } => {
+                write!(f, "Unsupported mesh format '{extension}', expected obj, ply or stl")
+            }
+            Self::EmptyMesh => write!(f, "Mesh produced no triangles"),
+            Self::InconsistentBuffers { reason } => write!(f, "Mesh buffers are inconsistent: {reason}"),
             Self::MissingPositionIndex { model_name, index } => {
                 write!(f, "Model '{model_name}' referenced missing vertex index {index}")
             }
@@ -43,3 +62,37 @@ impl Display for MeshLoadError {
 }
 
 impl Error for MeshLoadError {}
+
+#[derive(Debug)]
+pub enum SdfError {
+    Unbounded,
+    NoSurface,
+}
+
+impl Display for SdfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Unbounded => write!(f, "SDF is unbounded; intersect planes with a bounded shape"),
+            Self::NoSurface => write!(f, "SDF has no surface within its bounds"),
+        }
+    }
+}
+
+impl Error for SdfError {}
+
+#[derive(Debug)]
+pub enum CsgError {
+    OpenChild,
+    Empty,
+}
+
+impl Display for CsgError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::OpenChild => write!(f, "CSG children must be closed solids"),
+            Self::Empty => write!(f, "CSG operation leaves no surface"),
+        }
+    }
+}
+
+impl Error for CsgError {}
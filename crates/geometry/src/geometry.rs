@@ -1,30 +1,64 @@
 use rand::Rng;
 
 use crate::{
-    aabb::Aabb, bounded::Bounded, capsule::Capsule, circle::Circle, contact::Contact, mesh::Mesh, quad::Quad, ray::Ray,
-    sample::Sample, sampleable::Sampleable, sphere::Sphere, torus::Torus, traceable::Traceable, triangle::Triangle,
+    aabb::Aabb, annulus::Annulus, bounded::Bounded, capsule::Capsule, circle::Circle, cone::Cone, contact::Contact,
+    csg::Csg, cylinder::Cylinder, heightfield::Heightfield, mesh::Mesh, quad::Quad, ray::Ray, sample::Sample,
+    sampleable::Sampleable, sdf::Sdf, sphere::Sphere, torus::Torus, traceable::Traceable, triangle::Triangle,
 };
 
 pub enum Geometry {
     Aabb(Aabb),
+    Annulus(Annulus),
     Capsule(Capsule),
     Circle(Circle),
+    Cone(Cone),
+    Csg(Csg),
+    Cylinder(Cylinder),
+    Heightfield(Heightfield),
     Mesh(Mesh),
     Quad(Quad),
+    Sdf(Sdf),
     Sphere(Sphere),
     Torus(Torus),
     Triangle(Triangle),
 }
 
+impl Geometry {
+    // Whether the geometry encloses a volume and can take part in CSG.
+    #[must_use]
+    #[inline]
+    pub const fn is_solid(&self) -> bool {
+        match self {
+            Self::Aabb(_) | Self::Capsule(_) | Self::Csg(_) | Self::Sphere(_) => true,
+            Self::Cone(cone) => cone.is_capped(),
+            Self::Mesh(mesh) => mesh.is_closed(),
+            Self::Cylinder(cylinder) => cylinder.is_capped(),
+            Self::Annulus(_)
+            | Self::Circle(_)
+            | Self::Heightfield(_)
+            | Self::Quad(_)
+            | Self::Sdf(_)
+            | Self::Torus(_)
+            | Self::Triangle(_) => false,
+        }
+    }
+}
+
 impl Bounded for Geometry {
     #[inline]
     fn bounds(&self) -> Aabb {
         match self {
             Self::Aabb(aabb) => aabb.bounds(),
+            Self::Annulus(annulus) => annulus.bounds(),
             Self::Capsule(capsule) => capsule.bounds(),
             Self::Circle(circle) => circle.bounds(),
+            Self::Cone(cone) => cone.bounds(),
+            Self::Csg(csg) => csg.bounds(),
+            Self::Cylinder(cylinder) => cylinder.bounds(),
+            Self::Heightfield(heightfield) => heightfield.bounds(),
             Self::Mesh(mesh) => mesh.bounds(),
             Self::Quad(quad) => quad.bounds(),
+            Self::Sdf(sdf) => sdf.bounds(),
             Self::Sphere(sphere) => sphere.bounds(),
             Self::Torus(torus) => torus.bounds(),
             Self::Triangle(triangle) => triangle.bounds(),
@@ -37,10 +71,16 @@ impl Traceable for Geometry {
     fn hit(&self, ray: &Ray, max_distance: f32) -> bool {
         match self {
             Self::Aabb(aabb) => aabb.hit(ray, max_distance),
+            Self::Annulus(annulus) => annulus.hit(ray, max_distance),
             Self::Capsule(capsule) => capsule.hit(ray, max_distance),
             Self::Circle(circle) => circle.hit(ray, max_distance),
+            Self::Cone(cone) => cone.hit(ray, max_distance),
+            Self::Csg(csg) => csg.hit(ray, max_distance),
+            Self::Cylinder(cylinder) => cylinder.hit(ray, max_distance),
+            Self::Heightfield(heightfield) => heightfield.hit(ray, max_distance),
             Self::Mesh(mesh) => mesh.hit(ray, max_distance),
             Self::Quad(quad) => quad.hit(ray, max_distance),
+            Self::Sdf(sdf) => sdf.hit(ray, max_distance),
             Self::Sphere(sphere) => sphere.hit(ray, max_distance),
             Self::Torus(torus) => torus.hit(ray, max_distance),
             Self::Triangle(triangle) => triangle.hit(ray, max_distance),
@@ -51,10 +91,16 @@ impl Traceable for Geometry {
     fn distance(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
         match self {
             Self::Aabb(aabb) => aabb.distance(ray, max_distance),
+            Self::Annulus(annulus) => annulus.distance(ray, max_distance),
             Self::Capsule(capsule) => capsule.distance(ray, max_distance),
             Self::Circle(circle) => circle.distance(ray, max_distance),
+            Self::Cone(cone) => cone.distance(ray, max_distance),
+            Self::Csg(csg) => csg.distance(ray, max_distance),
+            Self::Cylinder(cylinder) => cylinder.distance(ray, max_distance),
+            Self::Heightfield(heightfield) => heightfield.distance(ray, max_distance),
             Self::Mesh(mesh) => mesh.distance(ray, max_distance),
             Self::Quad(quad) => quad.distance(ray, max_distance),
+            Self::Sdf(sdf) => sdf.distance(ray, max_distance),
             Self::Sphere(sphere) => sphere.distance(ray, max_distance),
             Self::Torus(torus) => torus.distance(ray, max_distance),
             Self::Triangle(triangle) => triangle.distance(ray, max_distance),
@@ -65,15 +111,41 @@ impl Traceable for Geometry {
     fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact> {
         match self {
             Self::Aabb(aabb) => aabb.intersection(ray, max_distance),
+            Self::Annulus(annulus) => annulus.intersection(ray, max_distance),
             Self::Capsule(capsule) => capsule.intersection(ray, max_distance),
             Self::Circle(circle) => circle.intersection(ray, max_distance),
+            Self::Cone(cone) => cone.intersection(ray, max_distance),
+            Self::Csg(csg) => csg.intersection(ray, max_distance),
+            Self::Cylinder(cylinder) => cylinder.intersection(ray, max_distance),
+            Self::Heightfield(heightfield) => heightfield.intersection(ray, max_distance),
             Self::Mesh(mesh) => mesh.intersection(ray, max_distance),
             Self::Quad(quad) => quad.intersection(ray, max_distance),
+            Self::Sdf(sdf) => sdf.intersection(ray, max_distance),
             Self::Sphere(sphere) => sphere.intersection(ray, max_distance),
             Self::Torus(torus) => torus.intersection(ray, max_distance),
             Self::Triangle(triangle) => triangle.intersection(ray, max_distance),
         }
     }
+
+    #[inline]
+    fn crossings(&self, ray: &Ray) -> Option<Vec<Contact>> {
+        match self {
+            Self::Aabb(aabb) => aabb.crossings(ray),
+            Self::Capsule(capsule) => capsule.crossings(ray),
+            Self::Cone(cone) => cone.crossings(ray),
+            Self::Csg(csg) => csg.crossings(ray),
+            Self::Cylinder(cylinder) => cylinder.crossings(ray),
+            Self::Mesh(mesh) => mesh.crossings(ray),
+            Self::Sphere(sphere) => sphere.crossings(ray),
+            Self::Annulus(_)
+            | Self::Circle(_)
+            | Self::Heightfield(_)
+            | Self::Quad(_)
+            | Self::Sdf(_)
+            | Self::Torus(_)
+            | Self::Triangle(_) => None,
+        }
+    }
 }
 
 impl Sampleable for Geometry {
@@ -81,10 +153,16 @@ impl Sampleable for Geometry {
     fn area(&self) -> f32 {
         match self {
             Self::Aabb(aabb) => aabb.area(),
+            Self::Annulus(annulus) => annulus.area(),
             Self::Capsule(capsule) => capsule.area(),
             Self::Circle(circle) => circle.area(),
+            Self::Cone(cone) => cone.area(),
+            Self::Csg(csg) => csg.area(),
+            Self::Cylinder(cylinder) => cylinder.area(),
+            Self::Heightfield(heightfield) => heightfield.area(),
             Self::Mesh(mesh) => mesh.area(),
             Self::Quad(quad) => quad.area(),
+            Self::Sdf(sdf) => sdf.area(),
             Self::Sphere(sphere) => sphere.area(),
             Self::Torus(torus) => torus.area(),
             Self::Triangle(triangle) => triangle.area(),
@@ -95,10 +173,16 @@ impl Sampleable for Geometry {
     fn sample<R: Rng>(&self, rng: &mut R) -> Sample {
         match self {
             Self::Aabb(aabb) => aabb.sample(rng),
+            Self::Annulus(annulus) => annulus.sample(rng),
             Self::Capsule(capsule) => capsule.sample(rng),
             Self::Circle(circle) => circle.sample(rng),
+            Self::Cone(cone) => cone.sample(rng),
+            Self::Csg(csg) => csg.sample(rng),
+            Self::Cylinder(cylinder) => cylinder.sample(rng),
+            Self::Heightfield(heightfield) => heightfield.sample(rng),
             Self::Mesh(mesh) => mesh.sample(rng),
             Self::Quad(quad) => quad.sample(rng),
+            Self::Sdf(sdf) => sdf.sample(rng),
             Self::Sphere(sphere) => sphere.sample(rng),
             Self::Torus(torus) => torus.sample(rng),
             Self::Triangle(triangle) => triangle.sample(rng),
@@ -113,6 +197,13 @@ impl From<Aabb> for Geometry {
     }
 }
 
+impl From<Annulus> for Geometry {
+    #[inline]
+    fn from(val: Annulus) -> Self {
+        Self::Annulus(val)
+    }
+}
+
 impl From<Capsule> for Geometry {
     #[inline]
     fn from(val: Capsule) -> Self {
@@ -127,6 +218,34 @@ impl From<Circle> for Geometry {
     }
 }
 
+impl From<Cone> for Geometry {
+    #[inline]
+    fn from(val: Cone) -> Self {
+        Self::Cone(val)
+    }
+}
+
+impl From<Csg> for Geometry {
+    #[inline]
+    fn from(val: Csg) -> Self {
+        Self::Csg(val)
+    }
+}
+
+impl From<Cylinder> for Geometry {
+    #[inline]
+    fn from(val: Cylinder) -> Self {
+        Self::Cylinder(val)
+    }
+}
+
+impl From<Heightfield> for Geometry {
+    #[inline]
+    fn from(val: Heightfield) -> Self {
+        Self::Heightfield(val)
+    }
+}
+
 impl From<Mesh> for Geometry {
     #[inline]
     fn from(val: Mesh) -> Self {
@@ -141,6 +260,13 @@ impl From<Quad> for Geometry {
     }
 }
 
+impl From<Sdf> for Geometry {
+    #[inline]
+    fn from(val: Sdf) -> Self {
+        Self::Sdf(val)
+    }
+}
+
 impl From<Sphere> for Geometry {
     #[inline]
     fn from(val: Sphere) -> Self {
@@ -0,0 +1,303 @@
+use antler_grid::SurfaceGrid;
+use nalgebra::{Point2, Point3, Unit, Vector2, Vector3};
+use rand::{Rng, RngExt};
+
+use crate::{
+    aabb::Aabb, bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, ray::Ray, sample::Sample,
+    sampleable::Sampleable, traceable::Traceable, triangle::Triangle,
+};
+
+const BOUNDS_PADDING: f32 = 1.0e-4;
+const STACK_SIZE: usize = 128;
+
+// Each cell is split along its diagonal into two triangles, given as vertex offsets from the cell's lower corner.
+const CELL_TRIANGLES: [[[usize; 2]; 3]; 2] = [[[0, 0], [1, 0], [1, 1]], [[0, 0], [1, 1], [0, 1]]];
+
+// A regular grid of heights over the xy plane, centred on the origin with z up.
+pub struct Heightfield {
+    heights: SurfaceGrid<f32>,
+    size: Vector2<f32>,
+    // Minimum and maximum heights over square blocks of cells, from single cells up to the whole field.
+    levels: Vec<SurfaceGrid<[f32; 2]>>,
+    area_cdf: Vec<f32>,
+    total_area: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Node {
+    level: usize,
+    cell: [usize; 2],
+    entry: f32,
+}
+
+impl Heightfield {
+    #[must_use]
+    pub fn new(heights: SurfaceGrid<f32>, size: [f32; 2], height: f32) -> Self {
+        let [columns, rows] = heights.size();
+        assert!(columns >= 2 && rows >= 2, "Heightfield needs at least 2x2 samples");
+        assert!(size[0] > 0.0 && size[1] > 0.0, "Heightfield size must be positive");
+        assert!(height.is_finite(), "Heightfield height scale must be finite");
+
+        let heights = SurfaceGrid::from_vec(
+            [columns, rows],
+            heights.into_vec().into_iter().map(|sample| sample * height).collect(),
+        );
+
+        let mut heightfield = Self {
+            heights,
+            size: Vector2::new(size[0], size[1]),
+            levels: Vec::new(),
+            area_cdf: Vec::new(),
+            total_area: 0.0,
+        };
+
+        heightfield.build_levels();
+        heightfield.build_area_cdf();
+
+        heightfield
+    }
+
+    #[inline]
+    const fn cells(&self) -> [usize; 2] {
+        let [columns, rows] = self.heights.size();
+        [columns - 1, rows - 1]
+    }
+
+    fn build_levels(&mut self) {
+        let [cells_x, cells_y] = self.cells();
+
+        let mut base = Vec::with_capacity(cells_x * cells_y);
+        for y in 0..cells_y {
+            for x in 0..cells_x {
+                let corners = [[x, y], [x + 1, y], [x, y + 1], [x + 1, y + 1]].map(|vertex| self.heights[vertex]);
+                base.push([
+                    corners.into_iter().fold(f32::INFINITY, f32::min),
+                    corners.into_iter().fold(f32::NEG_INFINITY, f32::max),
+                ]);
+            }
+        }
+        self.levels.push(SurfaceGrid::from_vec([cells_x, cells_y], base));
+
+        // Each coarser level halves the resolution, keeping a partial block wherever the finer size is odd.
+        while let Some(finer) = self.levels.last()
+            && finer.size() != [1, 1]
+        {
+            let [width, height] = finer.size().map(|extent| extent.div_ceil(2));
+
+            let mut coarser = Vec::with_capacity(width * height);
+            for y in 0..height {
+                for x in 0..width {
+                    coarser.push(self.children(self.levels.len(), [x, y]).fold(
+                        [f32::INFINITY, f32::NEG_INFINITY],
+                        |[min, max], child| {
+                            let [child_min, child_max] = finer[child];
+                            [min.min(child_min), max.max(child_max)]
+                        },
+                    ));
+                }
+            }
+
+            self.levels.push(SurfaceGrid::from_vec([width, height], coarser));
+        }
+    }
+
+    fn build_area_cdf(&mut self) {
+        let [cells_x, cells_y] = self.cells();
+
+        let mut total_area = 0.0;
+        self.area_cdf = (0..cells_x * cells_y)
+            .map(|index| {
+                total_area += self.cell_areas([index % cells_x, index / cells_x]).iter().sum::<f32>();
+                total_area
+            })
+            .collect();
+        self.total_area = total_area;
+    }
+
+    // Blocks at a level cover the cells of up to four blocks on the level below.
+    fn children(&self, level: usize, block: [usize; 2]) -> impl Iterator<Item = [usize; 2]> + use<> {
+        let [width, height] = self.levels[level - 1].size();
+
+        [[0, 0], [1, 0], [0, 1], [1, 1]]
+            .into_iter()
+            .map(move |[dx, dy]| [block[0] * 2 + dx, block[1] * 2 + dy])
+            .filter(move |&[x, y]| x < width && y < height)
+    }
+
+    #[inline]
+    fn vertex(&self, [x, y]: [usize; 2]) -> Point3<f32> {
+        let [cells_x, cells_y] = self.cells();
+
+        Point3::new(
+            (x as f32 / cells_x as f32 - 0.5) * self.size.x,
+            (y as f32 / cells_y as f32 - 0.5) * self.size.y,
+            self.heights[[x, y]],
+        )
+    }
+
+    // Central differences of the neighbouring heights, falling back to one-sided differences along the edges.
+    fn vertex_normal(&self, [x, y]: [usize; 2]) -> Unit<Vector3<f32>> {
+        let [columns, rows] = self.heights.size();
+
+        let (left, right) = (x.saturating_sub(1), (x + 1).min(columns - 1));
+        let (down, up) = (y.saturating_sub(1), (y + 1).min(rows - 1));
+
+        let dx = self.vertex([right, y]) - self.vertex([left, y]);
+        let dy = self.vertex([x, up]) - self.vertex([x, down]);
+
+        Unit::new_normalize(Vector3::new(-dx.z / dx.x, -dy.z / dy.y, 1.0))
+    }
+
+    #[inline]
+    fn vertex_uv(&self, [x, y]: [usize; 2]) -> Point2<f32> {
+        let [cells_x, cells_y] = self.cells();
+        Point2::new(x as f32 / cells_x as f32, y as f32 / cells_y as f32)
+    }
+
+    fn triangle(&self, cell: [usize; 2], index: usize, shading: bool) -> Triangle {
+        let vertices = CELL_TRIANGLES[index].map(|[dx, dy]| [cell[0] + dx, cell[1] + dy]);
+
+        Triangle::new(
+            vertices.map(|vertex| self.vertex(vertex)),
+            shading.then(|| vertices.map(|vertex| self.vertex_normal(vertex))),
+            shading.then(|| vertices.map(|vertex| self.vertex_uv(vertex))),
+        )
+    }
+
+    fn cell_areas(&self, cell: [usize; 2]) -> [f32; 2] {
+        [0, 1].map(|index| self.triangle(cell, index, false).area())
+    }
+
+    fn block_bounds(&self, level: usize, block: [usize; 2]) -> Aabb {
+        let [cells_x, cells_y] = self.cells();
+        let span = 1 << level;
+
+        let min = [block[0] * span, block[1] * span];
+        let max = [
+            ((block[0] + 1) * span).min(cells_x),
+            ((block[1] + 1) * span).min(cells_y),
+        ];
+        let [min_height, max_height] = self.levels[level][block];
+
+        let (low, high) = (self.vertex(min), self.vertex(max));
+
+        Aabb::new(
+            Point3::new(low.x, low.y, min_height - BOUNDS_PADDING),
+            Point3::new(high.x, high.y, max_height + BOUNDS_PADDING),
+        )
+    }
+
+    #[inline]
+    fn entry(&self, ray: &Ray, level: usize, block: [usize; 2], max_distance: f32) -> Option<Node> {
+        let (near, far) = self.block_bounds(level, block).ray_interval(ray)?;
+
+        (far >= MIN_RAY_DISTANCE && near < max_distance).then_some(Node {
+            level,
+            cell: block,
+            entry: near.max(0.0),
+        })
+    }
+
+    // Walks the min-max pyramid front to back, only testing the triangles of cells whose height range the ray crosses.
+    fn trace(&self, ray: &Ray, max_distance: f32, any: bool) -> Option<(f32, [usize; 2], usize)> {
+        let mut best = max_distance;
+        let mut nearest = None;
+
+        let root = self.entry(ray, self.levels.len() - 1, [0, 0], best);
+
+        let mut stack = [Node::default(); STACK_SIZE];
+        let mut len = usize::from(root.is_some());
+        stack[0] = root.unwrap_or_default();
+
+        while len > 0 {
+            len -= 1;
+            let node = stack[len];
+
+            if node.entry >= best {
+                continue;
+            }
+
+            if node.level == 0 {
+                for index in 0..2 {
+                    if let Some(distance) = self.triangle(node.cell, index, false).distance(ray, best) {
+                        best = distance;
+                        nearest = Some((distance, node.cell, index));
+
+                        if any {
+                            return nearest;
+                        }
+                    }
+                }
+                continue;
+            }
+
+            let mut children = [Node::default(); 4];
+            let mut count = 0;
+            for child in self.children(node.level, node.cell) {
+                if let Some(child) = self.entry(ray, node.level - 1, child, best) {
+                    children[count] = child;
+                    count += 1;
+                }
+            }
+
+            // The nearest child is pushed last so it is visited first.
+            children[..count].sort_unstable_by(|a, b| b.entry.total_cmp(&a.entry));
+            stack[len..len + count].copy_from_slice(&children[..count]);
+            len += count;
+        }
+
+        nearest
+    }
+}
+
+impl Bounded for Heightfield {
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        self.block_bounds(self.levels.len() - 1, [0, 0])
+    }
+}
+
+impl Traceable for Heightfield {
+    #[inline]
+    fn hit(&self, ray: &Ray, max_distance: f32) -> bool {
+        self.trace(ray, max_distance, true).is_some()
+    }
+
+    #[inline]
+    fn distance(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
+        self.trace(ray, max_distance, false).map(|(distance, _, _)| distance)
+    }
+
+    #[inline]
+    fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact> {
+        let (_, cell, index) = self.trace(ray, max_distance, false)?;
+
+        self.triangle(cell, index, true).intersection(ray, f32::INFINITY)
+    }
+}
+
+impl Sampleable for Heightfield {
+    #[inline]
+    fn area(&self) -> f32 {
+        self.total_area
+    }
+
+    #[inline]
+    fn sample<R: Rng>(&self, rng: &mut R) -> Sample {
+        let target = rng.random::<f32>() * self.total_area;
+
+        let index = self
+            .area_cdf
+            .partition_point(|&area| area < target)
+            .min(self.area_cdf.len() - 1);
+        let cell = [index % self.cells()[0], index / self.cells()[0]];
+
+        let [first, second] = self.cell_areas(cell);
+        let triangle = usize::from(rng.random::<f32>() * (first + second) >= first);
+
+        Sample {
+            pdf_area: 1.0 / self.total_area,
+            ..self.triangle(cell, triangle, true).sample(rng)
+        }
+    }
+}
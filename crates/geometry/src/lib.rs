@@ -1,38 +1,61 @@
 mod aabb;
+mod annulus;
 mod bounded;
 mod bvh;
 mod capsule;
 mod circle;
+mod cone;
 mod config;
 mod contact;
+mod crossings;
+mod csg;
+mod cylinder;
 pub mod errors;
 mod geometry;
+mod heightfield;
 mod mesh;
+mod mesh_buffers;
+mod mesh_repair;
+mod obj;
 mod plane;
+mod ply;
 mod quad;
 mod ray;
 mod sample;
 mod sampleable;
+mod sdf;
 mod sphere;
+mod stl;
 mod torus;
 mod traceable;
 mod traversal_ray;
 mod triangle;
 pub mod utils;
+mod vertex_attribute;
 
 pub use aabb::Aabb;
+pub use annulus::Annulus;
 pub use bounded::Bounded;
 pub use bvh::Bvh;
 pub use capsule::Capsule;
 pub use circle::Circle;
+pub use cone::Cone;
 pub use contact::Contact;
+pub use csg::{Csg, CsgOperation};
+pub use cylinder::Cylinder;
 pub use geometry::Geometry;
+pub use heightfield::Heightfield;
 pub use mesh::Mesh;
+pub use mesh_buffers::MeshBuffers;
+pub use mesh_repair::{MeshRepair, RepairSummary};
+pub use obj::{ObjGroup, ObjMaterial, load_obj_groups};
 pub use quad::Quad;
 pub use ray::Ray;
 pub use sample::Sample;
 pub use sampleable::Sampleable;
+pub use sdf::{Sdf, SdfNode, SdfPrimitive};
 pub use sphere::Sphere;
 pub use torus::Torus;
 pub use traceable::Traceable;
 pub use triangle::Triangle;
+pub use vertex_attribute::VertexAttribute;
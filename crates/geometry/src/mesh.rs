@@ -1,151 +1,127 @@
-use std::{fmt::Debug, path::Path};
+use std::{
+    ffi::OsStr,
+    fmt::Debug,
+    path::{Path, PathBuf},
+};
 
-use nalgebra::{Point2, Point3, Unit, Vector3};
 use rand::{Rng, RngExt};
-use tobj::{LoadOptions, load_obj};
 
 use crate::{
-    aabb::Aabb, bounded::Bounded, bvh::Bvh, contact::Contact, errors::MeshLoadError, ray::Ray, sample::Sample,
-    sampleable::Sampleable, traceable::Traceable, triangle::Triangle,
+    aabb::Aabb,
+    bounded::Bounded,
+    bvh::Bvh,
+    config::MIN_RAY_DISTANCE,
+    contact::Contact,
+    crossings::lead_in,
+    errors::MeshLoadError,
+    mesh_buffers::MeshBuffers,
+    mesh_repair::{MeshRepair, RepairSummary},
+    obj, ply,
+    ray::Ray,
+    sample::Sample,
+    sampleable::Sampleable,
+    stl,
+    traceable::Traceable,
+    triangle::Triangle,
 };
 
+// Triangles are stored as indices into shared vertex arrays and only assembled when a ray reaches them.
 pub struct Mesh {
-    triangles: Vec<Triangle>,
-    bvh: Bvh<usize>,
+    buffers: MeshBuffers,
+    bvh: Bvh<u32>,
     total_area: f32,
     area_cdf: Vec<f32>,
+    closed: bool,
+    repaired: Option<(PathBuf, RepairSummary)>,
 }
 
 impl Mesh {
-    #[must_use]
-    pub fn new(triangles: Vec<Triangle>) -> Self {
-        assert!(!triangles.is_empty(), "Cannot build a mesh with no triangles.");
+    pub fn new(buffers: MeshBuffers) -> Result<Self, MeshLoadError> {
+        if buffers.is_empty() {
+            return Err(MeshLoadError::EmptyMesh);
+        }
+        buffers
+            .validate()
+            .map_err(|reason| MeshLoadError::InconsistentBuffers { reason })?;
 
+        let count = buffers.triangles.len();
         let bvh = Bvh::new(
-            triangles
-                .iter()
-                .enumerate()
-                .map(|(index, triangle)| (triangle.bounds(), index))
+            (0..count)
+                .map(|index| (buffers.shape(index).bounds(), index as u32))
                 .collect(),
         );
 
         let mut total_area = 0.0;
-        let mut area_cdf = Vec::with_capacity(triangles.len());
-        for triangle in &triangles {
-            total_area += triangle.area();
+        let mut area_cdf = Vec::with_capacity(count);
+        for index in 0..count {
+            total_area += buffers.shape(index).area();
             area_cdf.push(total_area);
         }
 
-        Self {
-            triangles,
+        Ok(Self {
+            closed: buffers.is_closed(),
+            buffers,
             bvh,
             total_area,
             area_cdf,
-        }
+            repaired: None,
+        })
     }
 
     #[must_use]
     #[inline]
-    pub fn triangle(&self, index: usize) -> &Triangle {
-        &self.triangles[index]
+    pub const fn buffers(&self) -> &MeshBuffers {
+        &self.buffers
     }
 
-    pub fn load<P: AsRef<Path> + Debug>(path: P) -> Result<Self, MeshLoadError> {
-        let (models, _materials) = load_obj(
-            path,
-            &LoadOptions {
-                triangulate: true,
-                single_index: true,
-                ..Default::default()
-            },
-        )?;
-
-        let mut triangles = Vec::new();
-
-        for model in models {
-            let mesh = model.mesh;
-
-            if mesh.indices.len() % 3 != 0 {
-                return Err(MeshLoadError::InvalidIndexCount {
-                    model_name: model.name,
-                    count: mesh.indices.len(),
-                });
-            }
-
-            for face in mesh.indices.chunks_exact(3) {
-                let i0 = face[0] as usize;
-                let i1 = face[1] as usize;
-                let i2 = face[2] as usize;
-
-                let vertices = [
-                    read_position(&mesh.positions, i0).ok_or_else(|| MeshLoadError::MissingPositionIndex {
-                        model_name: model.name.clone(),
-                        index: i0,
-                    })?,
-                    read_position(&mesh.positions, i1).ok_or_else(|| MeshLoadError::MissingPositionIndex {
-                        model_name: model.name.clone(),
-                        index: i1,
-                    })?,
-                    read_position(&mesh.positions, i2).ok_or_else(|| MeshLoadError::MissingPositionIndex {
-                        model_name: model.name.clone(),
-                        index: i2,
-                    })?,
-                ];
-
-                let normals = if mesh.normals.is_empty() {
-                    None
-                } else {
-                    Some([
-                        read_normal(&mesh.normals, i0).ok_or_else(|| MeshLoadError::MissingNormalIndex {
-                            model_name: model.name.clone(),
-                            index: i0,
-                        })?,
-                        read_normal(&mesh.normals, i1).ok_or_else(|| MeshLoadError::MissingNormalIndex {
-                            model_name: model.name.clone(),
-                            index: i1,
-                        })?,
-                        read_normal(&mesh.normals, i2).ok_or_else(|| MeshLoadError::MissingNormalIndex {
-                            model_name: model.name.clone(),
-                            index: i2,
-                        })?,
-                    ])
-                };
+    // Whether the surface is watertight, so that crossing counts give inside/outside parity.
+    #[must_use]
+    #[inline]
+    pub const fn is_closed(&self) -> bool {
+        self.closed
+    }
 
-                let uvs = if mesh.texcoords.is_empty() {
-                    None
-                } else {
-                    Some([
-                        read_uv(&mesh.texcoords, i0).ok_or_else(|| MeshLoadError::MissingTexcoordIndex {
-                            model_name: model.name.clone(),
-                            index: i0,
-                        })?,
-                        read_uv(&mesh.texcoords, i1).ok_or_else(|| MeshLoadError::MissingTexcoordIndex {
-                            model_name: model.name.clone(),
-                            index: i1,
-                        })?,
-                        read_uv(&mesh.texcoords, i2).ok_or_else(|| MeshLoadError::MissingTexcoordIndex {
-                            model_name: model.name.clone(),
-                            index: i2,
-                        })?,
-                    ])
-                };
+    #[must_use]
+    #[inline]
+    pub fn triangle(&self, index: usize) -> Triangle {
+        self.buffers.triangle(index)
+    }
 
-                triangles.push(Triangle::new(vertices, normals, uvs));
-            }
-        }
+    // Most candidates miss, so shading attributes are only gathered once the positions alone report a hit.
+    #[inline]
+    fn contact(&self, index: u32, ray: &Ray, max_distance: f32) -> Option<Contact> {
+        let index = index as usize;
+        self.buffers.shape(index).distance(ray, max_distance)?;
+        self.triangle(index).intersection(ray, max_distance)
+    }
 
-        if triangles.is_empty() {
-            return Err(MeshLoadError::EmptyMesh);
-        }
+    pub fn load<P: AsRef<Path> + Debug>(path: P) -> Result<Self, MeshLoadError> {
+        Self::new(read_buffers(path.as_ref())?)
+    }
+
+    // The summary is kept so it can be reported once the scene has been built.
+    pub fn load_repaired<P: AsRef<Path> + Debug>(path: P, repair: &MeshRepair) -> Result<Self, MeshLoadError> {
+        let path = path.as_ref();
+        let mut buffers = read_buffers(path)?;
+        let summary = buffers.repair(repair);
+
+        Ok(Self {
+            repaired: Some((path.to_path_buf(), summary)),
+            ..Self::new(buffers)?
+        })
+    }
 
-        Ok(Self::new(triangles))
+    #[must_use]
+    #[inline]
+    pub fn repair_summary(&self) -> Option<(&Path, &RepairSummary)> {
+        self.repaired.as_ref().map(|(path, summary)| (path.as_path(), summary))
     }
 }
 
 impl Bounded for Mesh {
     #[inline]
     fn bounds(&self) -> Aabb {
-        Aabb::union(self.triangles.iter().map(Bounded::bounds))
+        self.bvh.bounds()
     }
 }
 
@@ -155,7 +131,7 @@ impl Traceable for Mesh {
         let mut limit = max_distance;
 
         self.bvh.any_with_limit(ray, &mut limit, |triangle_index, limit| {
-            self.triangle(triangle_index).hit(ray, *limit)
+            self.buffers.shape(triangle_index as usize).hit(ray, *limit)
         })
     }
 
@@ -165,7 +141,11 @@ impl Traceable for Mesh {
 
         self.bvh
             .nearest_with_max(ray, &mut best_distance, |triangle_index, best_distance| {
-                if let Some(distance) = self.triangle(triangle_index).distance(ray, *best_distance) {
+                if let Some(distance) = self
+                    .buffers
+                    .shape(triangle_index as usize)
+                    .distance(ray, *best_distance)
+                {
                     *best_distance = distance;
                 }
 
@@ -182,7 +162,7 @@ impl Traceable for Mesh {
 
         self.bvh
             .nearest_with_max(ray, &mut best_distance, |triangle_index, best_distance| {
-                let Some(contact) = self.triangle(triangle_index).intersection(ray, *best_distance) else {
+                let Some(contact) = self.contact(triangle_index, ray, *best_distance) else {
                     return true;
                 };
 
@@ -194,6 +174,34 @@ impl Traceable for Mesh {
 
         nearest
     }
+
+    // Only a closed mesh has an inside to cross into. Hits on edges shared by neighbouring triangles are counted once.
+    #[inline]
+    fn crossings(&self, ray: &Ray) -> Option<Vec<Contact>> {
+        self.closed.then(|| {
+            let Some((lead_in_ray, offset)) = lead_in(self, ray) else {
+                return Vec::new();
+            };
+
+            let mut contacts = Vec::new();
+            let mut limit = f32::INFINITY;
+
+            self.bvh
+                .nearest_with_max(&lead_in_ray, &mut limit, |triangle_index, limit| {
+                    if let Some(mut contact) = self.contact(triangle_index, &lead_in_ray, *limit) {
+                        contact.distance += offset;
+                        contacts.push(contact);
+                    }
+
+                    true
+                });
+
+            contacts.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+            contacts.dedup_by(|b, a| b.distance - a.distance <= MIN_RAY_DISTANCE);
+
+            contacts
+        })
+    }
 }
 
 impl Sampleable for Mesh {
@@ -209,33 +217,24 @@ impl Sampleable for Mesh {
         let index = self
             .area_cdf
             .partition_point(|&area| area < target)
-            .min(self.triangles.len() - 1);
+            .min(self.area_cdf.len() - 1);
 
-        self.triangles[index].sample(rng)
+        self.triangle(index).sample(rng)
     }
 }
 
-fn read_position(data: &[f32], index: usize) -> Option<Point3<f32>> {
-    let base = index * 3;
-    Some(Point3::new(
-        *data.get(base)?,
-        *data.get(base + 1)?,
-        *data.get(base + 2)?,
-    ))
-}
-
-fn read_normal(data: &[f32], index: usize) -> Option<Unit<Vector3<f32>>> {
-    let base = index * 3;
-    let normal = Vector3::new(*data.get(base)?, *data.get(base + 1)?, *data.get(base + 2)?);
-
-    if normal.norm_squared() <= 1.0e-12 {
-        None
-    } else {
-        Some(Unit::new_normalize(normal))
+// The loader is chosen by file extension.
+fn read_buffers(path: &Path) -> Result<MeshBuffers, MeshLoadError> {
+    let extension = path
+        .extension()
+        .and_then(OsStr::to_str)
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    match extension.as_str() {
+        "obj" => obj::load(path),
+        "ply" => ply::load(path),
+        "stl" => stl::load(path),
+        _ => Err(MeshLoadError::UnsupportedFormat { extension }),
     }
 }
-
-fn read_uv(data: &[f32], index: usize) -> Option<Point2<f32>> {
-    let base = index * 2;
-    Some(Point2::new(*data.get(base)?, *data.get(base + 1)?))
-}
@@ -0,0 +1,251 @@
+use std::collections::{BTreeMap, HashMap};
+
+use antler_colour::Rgb;
+use nalgebra::{Point2, Point3, Unit, Vector3};
+
+use crate::{triangle::Triangle, vertex_attribute::VertexAttribute};
+
+const MIN_NORMAL_LENGTH: f32 = 1.0e-6;
+
+// Vertex attributes in parallel arrays, with each triangle naming its three corners by index. Shading reads the
+// built-in channels; any other attribute a file carries is kept by name alongside them.
+#[derive(Debug, Clone, Default)]
+pub struct MeshBuffers {
+    pub positions: Vec<Point3<f32>>,
+    pub normals: Option<Vec<Vector3<f32>>>,
+    pub uvs: Option<Vec<Point2<f32>>>,
+    pub colours: Option<Vec<Rgb>>,
+    pub attributes: BTreeMap<String, VertexAttribute>,
+    pub triangles: Vec<[u32; 3]>,
+}
+
+impl MeshBuffers {
+    #[must_use]
+    #[inline]
+    pub const fn new(positions: Vec<Point3<f32>>, triangles: Vec<[u32; 3]>) -> Self {
+        Self {
+            positions,
+            normals: None,
+            uvs: None,
+            colours: None,
+            attributes: BTreeMap::new(),
+            triangles,
+        }
+    }
+
+    // Corners sharing an identical position are welded into one vertex.
+    #[must_use]
+    pub fn from_positions(corners: impl IntoIterator<Item = [Point3<f32>; 3]>) -> Self {
+        let mut buffers = Self::default();
+        let mut welded = HashMap::new();
+
+        for corners in corners {
+            let triangle = corners.map(|position| {
+                *welded.entry(position.coords.map(f32::to_bits)).or_insert_with(|| {
+                    buffers.positions.push(position);
+                    (buffers.positions.len() - 1) as u32
+                })
+            });
+            buffers.triangles.push(triangle);
+        }
+
+        buffers
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_normals(mut self, normals: Option<Vec<Vector3<f32>>>) -> Self {
+        self.normals = normals;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_uvs(mut self, uvs: Option<Vec<Point2<f32>>>) -> Self {
+        self.uvs = uvs;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_colours(mut self, colours: Option<Vec<Rgb>>) -> Self {
+        self.colours = colours;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_attribute(mut self, name: impl Into<String>, attribute: VertexAttribute) -> Self {
+        self.attributes.insert(name.into(), attribute);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_attributes(mut self, attributes: BTreeMap<String, VertexAttribute>) -> Self {
+        self.attributes.extend(attributes);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn attribute(&self, name: &str) -> Option<&VertexAttribute> {
+        self.attributes.get(name)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    // Closed when every edge is shared by exactly two triangles, ignoring triangles collapsed onto a line or point.
+    // Corners are matched by position rather than index, so seams where vertices were split for their normals or
+    // uvs do not open the surface.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        let mut ids = HashMap::new();
+        let vertices = self
+            .positions
+            .iter()
+            .map(|position| {
+                let next = ids.len();
+                *ids.entry(position.coords.map(f32::to_bits)).or_insert(next)
+            })
+            .collect::<Vec<_>>();
+
+        let mut edges = HashMap::<(usize, usize), usize>::new();
+        for triangle in &self.triangles {
+            let [a, b, c] = triangle.map(|index| vertices[index as usize]);
+            if a == b || b == c || c == a {
+                continue;
+            }
+
+            for (start, end) in [(a, b), (b, c), (c, a)] {
+                *edges.entry((start.min(end), start.max(end))).or_default() += 1;
+            }
+        }
+
+        !edges.is_empty() && edges.values().all(|&count| count == 2)
+    }
+
+    // An attribute only one side carries is padded on the other: zero normals fall back to the face normal, and
+    // named channels are padded with zeros. A named channel the two sides lay out differently is dropped.
+    pub fn append(&mut self, other: Self) {
+        let (count, other_count) = (self.positions.len(), other.positions.len());
+        let offset = count as u32;
+
+        merge(&mut self.normals, other.normals, count, other_count, Vector3::zeros());
+        merge(&mut self.uvs, other.uvs, count, other_count, Point2::origin());
+        merge(&mut self.colours, other.colours, count, other_count, Rgb::WHITE);
+        merge_attributes(&mut self.attributes, other.attributes, count, other_count);
+
+        self.positions.extend(other.positions);
+        self.triangles.extend(
+            other
+                .triangles
+                .into_iter()
+                .map(|triangle| triangle.map(|index| index + offset)),
+        );
+    }
+
+    // A degenerate normal at any corner falls back to the face normal for the whole triangle.
+    #[must_use]
+    pub(crate) fn triangle(&self, index: usize) -> Triangle {
+        let corners = self.triangles[index].map(|index| index as usize);
+
+        let normals = self
+            .normals
+            .as_ref()
+            .map(|normals| corners.map(|index| Unit::try_new(normals[index], MIN_NORMAL_LENGTH)))
+            .and_then(|[a, b, c]| Some([a?, b?, c?]));
+        let uvs = self.uvs.as_ref().map(|uvs| corners.map(|index| uvs[index]));
+        let colours = self.colours.as_ref().map(|colours| corners.map(|index| colours[index]));
+
+        Triangle::new(corners.map(|index| self.positions[index]), normals, uvs).with_colours(colours)
+    }
+
+    // Only the corner positions, for tests that never build a contact.
+    #[must_use]
+    #[inline]
+    pub(crate) fn shape(&self, index: usize) -> Triangle {
+        Triangle::new(
+            self.triangles[index].map(|index| self.positions[index as usize]),
+            None,
+            None,
+        )
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        let count = self.positions.len();
+
+        let lengths = [
+            ("normals", self.normals.as_ref().map(Vec::len)),
+            ("uvs", self.uvs.as_ref().map(Vec::len)),
+            ("colours", self.colours.as_ref().map(Vec::len)),
+        ];
+        if let Some((name, Some(length))) = lengths
+            .into_iter()
+            .find(|(_, length)| length.is_some_and(|length| length != count))
+        {
+            return Err(format!("{length} {name} for {count} positions"));
+        }
+
+        for (name, attribute) in &self.attributes {
+            if attribute.components == 0 || attribute.values.len() != attribute.components * count {
+                return Err(format!(
+                    "attribute '{name}' has {} values, not {} per vertex for {count} positions",
+                    attribute.values.len(),
+                    attribute.components
+                ));
+            }
+        }
+
+        if let Some(index) = self.triangles.iter().flatten().find(|&&index| index as usize >= count) {
+            return Err(format!("triangle refers to missing vertex {index}"));
+        }
+
+        Ok(())
+    }
+}
+
+fn merge<T: Copy>(values: &mut Option<Vec<T>>, other: Option<Vec<T>>, count: usize, other_count: usize, fill: T) {
+    match (values.as_mut(), other) {
+        (Some(values), Some(other)) => values.extend(other),
+        (Some(values), None) => values.extend(std::iter::repeat_n(fill, other_count)),
+        (None, Some(other)) => {
+            let mut padded = vec![fill; count];
+            padded.extend(other);
+            *values = Some(padded);
+        }
+        (None, None) => {}
+    }
+}
+
+fn merge_attributes(
+    attributes: &mut BTreeMap<String, VertexAttribute>,
+    mut other: BTreeMap<String, VertexAttribute>,
+    count: usize,
+    other_count: usize,
+) {
+    attributes.retain(|name, attribute| {
+        let Some(other) = other.remove(name) else {
+            attribute
+                .values
+                .extend(VertexAttribute::zeros(attribute.components, other_count).values);
+            return true;
+        };
+
+        let same_layout = other.components == attribute.components;
+        if same_layout {
+            attribute.values.extend(other.values);
+        }
+        same_layout
+    });
+
+    for (name, other) in other {
+        let mut attribute = VertexAttribute::zeros(other.components, count);
+        attribute.values.extend(other.values);
+        attributes.insert(name, attribute);
+    }
+}
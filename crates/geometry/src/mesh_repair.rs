@@ -0,0 +1,358 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+};
+
+use nalgebra::{Point3, Vector3};
+
+use crate::mesh_buffers::MeshBuffers;
+
+// Attributes of two vertices being welded must agree to within this, whatever the position tolerance.
+const ATTRIBUTE_TOLERANCE: f32 = 1.0e-5;
+
+// Clean-up steps run once after loading, in the order they are listed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeshRepair {
+    pub weld_tolerance: Option<f32>,
+    pub drop_degenerate: bool,
+    pub unify_winding: bool,
+    // Faces meeting at a sharper angle than this, in radians, keep a hard edge between them.
+    pub crease_angle: Option<f32>,
+}
+
+// What each requested step changed; steps that were not requested are left as `None`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RepairSummary {
+    pub vertices: usize,
+    pub triangles: usize,
+    pub welded_vertices: Option<usize>,
+    pub dropped_triangles: Option<usize>,
+    pub flipped_triangles: Option<usize>,
+    pub split_vertices: Option<usize>,
+}
+
+impl MeshBuffers {
+    pub fn repair(&mut self, options: &MeshRepair) -> RepairSummary {
+        // Normals about to be regenerated should not stop otherwise identical vertices from welding.
+        if options.crease_angle.is_some() {
+            self.normals = None;
+        }
+
+        let welded_vertices = options.weld_tolerance.map(|tolerance| self.weld(tolerance));
+        let dropped_triangles = options.drop_degenerate.then(|| self.drop_degenerate());
+        let flipped_triangles = options.unify_winding.then(|| self.unify_winding());
+        let split_vertices = options
+            .crease_angle
+            .map(|crease_angle| self.smooth_normals(crease_angle));
+
+        RepairSummary {
+            vertices: self.positions.len(),
+            triangles: self.triangles.len(),
+            welded_vertices,
+            dropped_triangles,
+            flipped_triangles,
+            split_vertices,
+        }
+    }
+
+    // Candidates are found through a grid of tolerance-sized cells, searching each neighbouring cell as well.
+    fn weld(&mut self, tolerance: f32) -> usize {
+        let cell = |position: &Point3<f32>| -> [i64; 3] {
+            if tolerance > 0.0 {
+                position.coords.map(|coord| (coord / tolerance).floor() as i64).into()
+            } else {
+                position.coords.map(|coord| i64::from(coord.to_bits())).into()
+            }
+        };
+
+        let mut cells: HashMap<[i64; 3], Vec<u32>> = HashMap::new();
+        let mut representatives = Vec::with_capacity(self.positions.len());
+        let mut welded = 0;
+
+        for index in 0..self.positions.len() {
+            let [x, y, z] = cell(&self.positions[index]);
+
+            let existing = (-1..=1)
+                .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| [x + dx, y + dy, z + dz])))
+                .filter_map(|key| cells.get(&key))
+                .flatten()
+                .copied()
+                .find(|&other| self.same_vertex(other as usize, index, tolerance));
+
+            if let Some(other) = existing {
+                representatives.push(other);
+                welded += 1;
+            } else {
+                representatives.push(index as u32);
+                cells.entry([x, y, z]).or_default().push(index as u32);
+            }
+        }
+
+        for corner in self.triangles.iter_mut().flatten() {
+            *corner = representatives[*corner as usize];
+        }
+        self.compact();
+
+        welded
+    }
+
+    fn same_vertex(&self, a: usize, b: usize, tolerance: f32) -> bool {
+        (self.positions[a] - self.positions[b]).norm() <= tolerance
+            && self
+                .normals
+                .as_ref()
+                .is_none_or(|normals| (normals[a] - normals[b]).norm() <= ATTRIBUTE_TOLERANCE)
+            && self
+                .uvs
+                .as_ref()
+                .is_none_or(|uvs| (uvs[a] - uvs[b]).norm() <= ATTRIBUTE_TOLERANCE)
+            && self.colours.as_ref().is_none_or(|colours| {
+                let (a, b) = (colours[a], colours[b]);
+                [a.red - b.red, a.green - b.green, a.blue - b.blue]
+                    .iter()
+                    .all(|difference| difference.abs() <= ATTRIBUTE_TOLERANCE)
+            })
+            && self.attributes.values().all(|attribute| {
+                attribute
+                    .get(a)
+                    .iter()
+                    .zip(attribute.get(b))
+                    .all(|(a, b)| (a - b).abs() <= ATTRIBUTE_TOLERANCE)
+            })
+    }
+
+    // Triangles with repeated corners or collinear ones cover no area and only produce grazing hits.
+    fn drop_degenerate(&mut self) -> usize {
+        let before = self.triangles.len();
+        let positions = &self.positions;
+
+        self.triangles.retain(|corners| {
+            let [a, b, c] = corners.map(|index| positions[index as usize]);
+            let longest = (b - a)
+                .norm_squared()
+                .max((c - a).norm_squared())
+                .max((c - b).norm_squared());
+
+            (b - a).cross(&(c - a)).norm() > f32::EPSILON * longest
+        });
+        self.compact();
+
+        before - self.triangles.len()
+    }
+
+    // Triangles sharing an edge should run along it in opposite directions. Each connected patch follows its first
+    // triangle, and patches that close up are then turned so their faces point outwards.
+    fn unify_winding(&mut self) -> usize {
+        let ids = self.position_ids();
+        let edge = |a: u32, b: u32| {
+            (
+                ids[a as usize].min(ids[b as usize]),
+                ids[a as usize].max(ids[b as usize]),
+            )
+        };
+
+        let mut edges: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+        for (index, &[a, b, c]) in self.triangles.iter().enumerate() {
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                edges.entry(edge(from, to)).or_default().push(index);
+            }
+        }
+
+        let runs_along = |corners: [u32; 3], from: u32, to: u32| {
+            let [a, b, c] = corners.map(|index| ids[index as usize]);
+            [(a, b), (b, c), (c, a)].contains(&(from, to))
+        };
+
+        let mut flips: Vec<Option<bool>> = vec![None; self.triangles.len()];
+        for seed in 0..self.triangles.len() {
+            if flips[seed].is_some() {
+                continue;
+            }
+
+            flips[seed] = Some(false);
+            let mut patch = vec![seed];
+            let mut queue = vec![seed];
+            let mut closed = true;
+
+            while let Some(index) = queue.pop() {
+                let [a, b, c] = oriented(self.triangles[index], flips[index] == Some(true));
+
+                for (from, to) in [(a, b), (b, c), (c, a)] {
+                    let sharing = &edges[&edge(from, to)];
+                    closed &= sharing.len() == 2;
+
+                    for &neighbour in sharing {
+                        if flips[neighbour].is_some() {
+                            continue;
+                        }
+
+                        let (from, to) = (ids[from as usize], ids[to as usize]);
+                        flips[neighbour] = Some(runs_along(self.triangles[neighbour], from, to));
+                        patch.push(neighbour);
+                        queue.push(neighbour);
+                    }
+                }
+            }
+
+            if closed && self.signed_volume(&patch, &flips) < 0.0 {
+                for &index in &patch {
+                    flips[index] = flips[index].map(|flip| !flip);
+                }
+            }
+        }
+
+        let mut flipped = 0;
+        for (corners, flip) in self.triangles.iter_mut().zip(flips) {
+            if flip == Some(true) {
+                *corners = oriented(*corners, true);
+                flipped += 1;
+            }
+        }
+
+        flipped
+    }
+
+    fn signed_volume(&self, patch: &[usize], flips: &[Option<bool>]) -> f32 {
+        patch
+            .iter()
+            .map(|&index| {
+                let [a, b, c] = oriented(self.triangles[index], flips[index] == Some(true))
+                    .map(|corner| self.positions[corner as usize].coords);
+                a.dot(&b.cross(&c))
+            })
+            .sum::<f32>()
+            / 6.0
+    }
+
+    // Each corner averages the area-weighted normals of the faces around its position that lie within the crease
+    // angle of its own face. Corners of one vertex that end up with different normals split it into several, and the
+    // number of extra vertices this adds is returned.
+    fn smooth_normals(&mut self, crease_angle: f32) -> usize {
+        let ids = self.position_ids();
+        let min_cosine = crease_angle.cos();
+
+        let faces: Vec<Vector3<f32>> = self
+            .triangles
+            .iter()
+            .map(|corners| {
+                let [a, b, c] = corners.map(|index| self.positions[index as usize]);
+                (b - a).cross(&(c - a))
+            })
+            .collect();
+        let directions: Vec<_> = faces.iter().map(|face| face.try_normalize(0.0)).collect();
+
+        let mut around = vec![Vec::new(); ids.iter().max().map_or(0, |&id| id as usize + 1)];
+        for (index, corners) in self.triangles.iter().enumerate() {
+            for &corner in corners {
+                let faces: &mut Vec<usize> = &mut around[ids[corner as usize] as usize];
+                if faces.last() != Some(&index) {
+                    faces.push(index);
+                }
+            }
+        }
+
+        let mut split: HashMap<(u32, [u32; 3]), u32> = HashMap::new();
+        let mut kept = Vec::new();
+        let mut normals = Vec::new();
+
+        for (index, corners) in self.triangles.iter_mut().enumerate() {
+            for corner in corners {
+                let normal = directions[index].map_or_else(Vector3::zeros, |direction| {
+                    around[ids[*corner as usize] as usize]
+                        .iter()
+                        .filter(|&&other| directions[other].is_some_and(|other| other.dot(&direction) >= min_cosine))
+                        .map(|&other| faces[other])
+                        .sum::<Vector3<f32>>()
+                        .try_normalize(0.0)
+                        .unwrap_or(direction)
+                });
+
+                *corner = *split
+                    .entry((*corner, normal.map(f32::to_bits).into()))
+                    .or_insert_with(|| {
+                        kept.push(*corner);
+                        normals.push(normal);
+                        (kept.len() - 1) as u32
+                    });
+            }
+        }
+
+        let mut used = vec![false; self.positions.len()];
+        for &vertex in &kept {
+            used[vertex as usize] = true;
+        }
+
+        self.keep_vertices(&kept);
+        self.normals = Some(normals);
+
+        kept.len() - used.into_iter().filter(|&used| used).count()
+    }
+
+    // Vertices at exactly the same position share an id, so seams split for texturing still count as connected.
+    fn position_ids(&self) -> Vec<u32> {
+        let mut ids = HashMap::new();
+
+        self.positions
+            .iter()
+            .map(|position| {
+                let count = ids.len() as u32;
+                *ids.entry(position.coords.map(f32::to_bits)).or_insert(count)
+            })
+            .collect()
+    }
+
+    // Drops vertices no triangle refers to, renumbering the rest in the order they are first used.
+    fn compact(&mut self) {
+        let mut renumbered = vec![u32::MAX; self.positions.len()];
+        let mut kept = Vec::new();
+
+        for corner in self.triangles.iter_mut().flatten() {
+            let old = *corner as usize;
+            if renumbered[old] == u32::MAX {
+                renumbered[old] = kept.len() as u32;
+                kept.push(*corner);
+            }
+            *corner = renumbered[old];
+        }
+
+        self.keep_vertices(&kept);
+    }
+
+    fn keep_vertices(&mut self, kept: &[u32]) {
+        self.positions = gather(&self.positions, kept);
+        self.normals = self.normals.as_deref().map(|normals| gather(normals, kept));
+        self.uvs = self.uvs.as_deref().map(|uvs| gather(uvs, kept));
+        self.colours = self.colours.as_deref().map(|colours| gather(colours, kept));
+        for attribute in self.attributes.values_mut() {
+            *attribute = attribute.gather(kept);
+        }
+    }
+}
+
+impl Display for RepairSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} vertices, {} triangles", self.vertices, self.triangles)?;
+
+        let steps = [
+            (self.welded_vertices, "vertices welded"),
+            (self.dropped_triangles, "degenerate triangles dropped"),
+            (self.flipped_triangles, "triangles flipped"),
+            (self.split_vertices, "vertices split at creases"),
+        ];
+        for (count, step) in steps {
+            if let Some(count) = count {
+                write!(f, ", {count} {step}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const fn oriented([a, b, c]: [u32; 3], flip: bool) -> [u32; 3] {
+    if flip { [a, c, b] } else { [a, b, c] }
+}
+
+fn gather<T: Copy>(values: &[T], kept: &[u32]) -> Vec<T> {
+    kept.iter().map(|&index| values[index as usize]).collect()
+}
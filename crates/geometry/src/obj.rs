@@ -0,0 +1,121 @@
+use std::path::Path;
+
+use nalgebra::{Point2, Point3, Vector3};
+pub use tobj::Material as ObjMaterial;
+use tobj::{LoadOptions, Model};
+
+use crate::{errors::MeshLoadError, mesh_buffers::MeshBuffers};
+
+// One object or material group of an OBJ file, with the index of its material in the file's library.
+pub struct ObjGroup {
+    pub name: String,
+    pub material: Option<usize>,
+    pub buffers: MeshBuffers,
+}
+
+// Every model is flattened into one list and the material library is ignored.
+pub fn load(path: &Path) -> Result<MeshBuffers, MeshLoadError> {
+    let (models, _materials) = tobj::load_obj(path, &load_options())?;
+
+    let mut buffers = MeshBuffers::default();
+    for model in models {
+        buffers.append(model_buffers(model)?);
+    }
+
+    Ok(buffers)
+}
+
+// A new group starts at each object, group or material change; groups without faces are dropped.
+// A missing or unreadable material library leaves every group on the default material rather than failing the load.
+pub fn load_obj_groups(path: &Path) -> Result<(Vec<ObjGroup>, Vec<ObjMaterial>), MeshLoadError> {
+    let (models, materials) = tobj::load_obj(path, &load_options())?;
+    let materials = materials.ok();
+
+    let mut groups = Vec::with_capacity(models.len());
+    for model in models {
+        let name = model.name.clone();
+        let material = model.mesh.material_id.filter(|_| materials.is_some());
+        let buffers = model_buffers(model)?;
+
+        if !buffers.is_empty() {
+            groups.push(ObjGroup {
+                name,
+                material,
+                buffers,
+            });
+        }
+    }
+
+    Ok((groups, materials.unwrap_or_default()))
+}
+
+fn load_options() -> LoadOptions {
+    LoadOptions {
+        triangulate: true,
+        single_index: true,
+        ..Default::default()
+    }
+}
+
+// With single indexing every attribute array is laid out per vertex, so the face indices carry over unchanged.
+fn model_buffers(model: Model) -> Result<MeshBuffers, MeshLoadError> {
+    let mesh = model.mesh;
+
+    if !mesh.indices.len().is_multiple_of(3) {
+        return Err(MeshLoadError::InvalidIndexCount {
+            model_name: model.name,
+            count: mesh.indices.len(),
+        });
+    }
+
+    let positions: Vec<_> = mesh
+        .positions
+        .chunks_exact(3)
+        .map(|value| Point3::new(value[0], value[1], value[2]))
+        .collect();
+    let normals: Vec<_> = mesh
+        .normals
+        .chunks_exact(3)
+        .map(|value| Vector3::new(value[0], value[1], value[2]))
+        .collect();
+    let uvs: Vec<_> = mesh
+        .texcoords
+        .chunks_exact(2)
+        .map(|value| Point2::new(value[0], value[1]))
+        .collect();
+
+    for &index in &mesh.indices {
+        let index = index as usize;
+        let model_name = || model.name.clone();
+
+        if index >= positions.len() {
+            return Err(MeshLoadError::MissingPositionIndex {
+                model_name: model_name(),
+                index,
+            });
+        }
+        if !normals.is_empty() && normals.get(index).is_none_or(|normal| normal.norm_squared() <= 1.0e-12) {
+            return Err(MeshLoadError::MissingNormalIndex {
+                model_name: model_name(),
+                index,
+            });
+        }
+        if !uvs.is_empty() && index >= uvs.len() {
+            return Err(MeshLoadError::MissingTexcoordIndex {
+                model_name: model_name(),
+                index,
+            });
+        }
+    }
+
+    let triangles = mesh
+        .indices
+        .chunks_exact(3)
+        .map(|face| [face[0], face[1], face[2]])
+        .collect();
+
+    // Normals and texture coordinates are only kept when every vertex has one.
+    Ok(MeshBuffers::new(positions, triangles)
+        .with_normals((!normals.is_empty()).then_some(normals))
+        .with_uvs((!uvs.is_empty()).then_some(uvs)))
+}
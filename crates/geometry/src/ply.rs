@@ -0,0 +1,471 @@
+use std::{fs::read, path::Path};
+
+use antler_colour::Rgb;
+use nalgebra::{Point2, Point3, Vector3};
+
+use crate::{errors::MeshLoadError, mesh_buffers::MeshBuffers, vertex_attribute::VertexAttribute};
+
+const END_HEADER: &[u8] = b"end_header";
+
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+#[derive(Debug)]
+enum Property {
+    Scalar { name: String, kind: Scalar },
+    List { name: String, count: Scalar, item: Scalar },
+}
+
+#[derive(Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+struct Body<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    format: Format,
+}
+
+// Column positions of the vertex attributes this loader understands, and of any other scalar properties, which
+// are kept as named single-component channels.
+struct VertexLayout {
+    position: [usize; 3],
+    normal: Option<[usize; 3]>,
+    uv: Option<[usize; 2]>,
+    colour: Option<([usize; 3], Scalar)>,
+    extra: Vec<(String, usize)>,
+}
+
+#[derive(Default)]
+struct Vertices {
+    positions: Vec<Point3<f32>>,
+    normals: Vec<Vector3<f32>>,
+    uvs: Vec<Point2<f32>>,
+    colours: Vec<Rgb>,
+    attributes: Vec<(String, Vec<f32>)>,
+}
+
+// Reads ASCII and binary PLY files, triangulating polygonal faces as fans around their first vertex.
+pub fn load(path: &Path) -> Result<MeshBuffers, MeshLoadError> {
+    let bytes = read(path)?;
+
+    let header_end = bytes
+        .windows(END_HEADER.len())
+        .position(|window| window == END_HEADER)
+        .ok_or_else(|| invalid("missing end_header"))?;
+    let body_start = bytes[header_end..]
+        .iter()
+        .position(|&byte| byte == b'\n')
+        .map_or(bytes.len(), |offset| header_end + offset + 1);
+
+    let (format, elements) = parse_header(&String::from_utf8_lossy(&bytes[..header_end]))?;
+
+    let mut body = Body {
+        bytes: &bytes[body_start..],
+        position: 0,
+        format,
+    };
+
+    let mut vertices = Vertices::default();
+    let mut faces = Vec::new();
+
+    for element in &elements {
+        match element.name.as_str() {
+            "vertex" => vertices = read_vertices(&mut body, element)?,
+            "face" => faces = read_faces(&mut body, element)?,
+            _ => {
+                for _ in 0..element.count {
+                    skip_row(&mut body, element)?;
+                }
+            }
+        }
+    }
+
+    let mut triangles = Vec::with_capacity(faces.len());
+
+    for face in faces {
+        if let Some(&missing) = face.iter().find(|&&index| index >= vertices.positions.len()) {
+            return Err(invalid(&format!("face refers to missing vertex {missing}")));
+        }
+
+        for k in 1..face.len().saturating_sub(1) {
+            triangles.push([face[0], face[k], face[k + 1]].map(|index| index as u32));
+        }
+    }
+
+    Ok(vertices.into_buffers(triangles))
+}
+
+fn invalid(reason: &str) -> MeshLoadError {
+    MeshLoadError::Ply {
+        reason: reason.to_owned(),
+    }
+}
+
+fn parse_header(header: &str) -> Result<(Format, Vec<Element>), MeshLoadError> {
+    let mut lines = header.lines().map(str::trim);
+
+    if lines.next() != Some("ply") {
+        return Err(invalid("missing ply magic number"));
+    }
+
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+
+    for line in lines {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+
+        match words.as_slice() {
+            ["format", name, _version] => {
+                format = Some(match *name {
+                    "ascii" => Format::Ascii,
+                    "binary_little_endian" => Format::BinaryLittleEndian,
+                    "binary_big_endian" => Format::BinaryBigEndian,
+                    other => return Err(invalid(&format!("unknown format '{other}'"))),
+                });
+            }
+            ["element", name, count] => elements.push(Element {
+                name: (*name).to_owned(),
+                count: count
+                    .parse()
+                    .map_err(|_| invalid(&format!("invalid count for element '{name}'")))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count, item, name] => elements
+                .last_mut()
+                .ok_or_else(|| invalid("property before any element"))?
+                .properties
+                .push(Property::List {
+                    name: (*name).to_owned(),
+                    count: Scalar::parse(count)?,
+                    item: Scalar::parse(item)?,
+                }),
+            ["property", kind, name] => elements
+                .last_mut()
+                .ok_or_else(|| invalid("property before any element"))?
+                .properties
+                .push(Property::Scalar {
+                    name: (*name).to_owned(),
+                    kind: Scalar::parse(kind)?,
+                }),
+            [] | ["comment" | "obj_info", ..] => {}
+            _ => return Err(invalid(&format!("unrecognised header line '{line}'"))),
+        }
+    }
+
+    Ok((format.ok_or_else(|| invalid("missing format line"))?, elements))
+}
+
+fn read_vertices(body: &mut Body, element: &Element) -> Result<Vertices, MeshLoadError> {
+    let layout = VertexLayout::new(element)?;
+    let mut row = vec![0.0; element.properties.len()];
+    let mut vertices = Vertices {
+        attributes: layout
+            .extra
+            .iter()
+            .map(|(name, _)| (name.clone(), Vec::with_capacity(body.capacity(element.count))))
+            .collect(),
+        ..Vertices::default()
+    };
+
+    for _ in 0..element.count {
+        for (value, property) in row.iter_mut().zip(&element.properties) {
+            *value = match property {
+                Property::Scalar { kind, .. } => body.read(*kind)?,
+                Property::List { .. } => {
+                    body.skip(property)?;
+                    0.0
+                }
+            };
+        }
+
+        let [x, y, z] = layout.position.map(|column| row[column] as f32);
+        vertices.positions.push(Point3::new(x, y, z));
+
+        if let Some(columns) = layout.normal {
+            let [x, y, z] = columns.map(|column| row[column] as f32);
+            vertices.normals.push(Vector3::new(x, y, z));
+        }
+
+        if let Some(columns) = layout.uv {
+            let [u, v] = columns.map(|column| row[column] as f32);
+            vertices.uvs.push(Point2::new(u, v));
+        }
+
+        if let Some((columns, kind)) = layout.colour {
+            let [red, green, blue] = columns.map(|column| (row[column] / kind.unit()) as f32);
+            vertices.colours.push(Rgb::new(red, green, blue));
+        }
+
+        for ((_, column), (_, values)) in layout.extra.iter().zip(&mut vertices.attributes) {
+            values.push(row[*column] as f32);
+        }
+    }
+
+    Ok(vertices)
+}
+
+fn read_faces(body: &mut Body, element: &Element) -> Result<Vec<Vec<usize>>, MeshLoadError> {
+    let mut faces = Vec::with_capacity(body.capacity(element.count));
+
+    for _ in 0..element.count {
+        let mut indices = None;
+
+        for property in &element.properties {
+            match property {
+                Property::List { name, count, item } if name == "vertex_indices" || name == "vertex_index" => {
+                    let count = body.read_count(*count)?;
+                    indices = Some(
+                        (0..count)
+                            .map(|_| body.read_count(*item))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    );
+                }
+                _ => body.skip(property)?,
+            }
+        }
+
+        faces.push(indices.ok_or_else(|| invalid("face element has no vertex_indices list"))?);
+    }
+
+    Ok(faces)
+}
+
+fn skip_row(body: &mut Body, element: &Element) -> Result<(), MeshLoadError> {
+    for property in &element.properties {
+        body.skip(property)?;
+    }
+
+    Ok(())
+}
+
+impl Scalar {
+    fn parse(name: &str) -> Result<Self, MeshLoadError> {
+        Ok(match name {
+            "char" | "int8" => Self::I8,
+            "uchar" | "uint8" => Self::U8,
+            "short" | "int16" => Self::I16,
+            "ushort" | "uint16" => Self::U16,
+            "int" | "int32" => Self::I32,
+            "uint" | "uint32" => Self::U32,
+            "float" | "float32" => Self::F32,
+            "double" | "float64" => Self::F64,
+            other => return Err(invalid(&format!("unknown property type '{other}'"))),
+        })
+    }
+
+    const fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+
+    // The value that represents full intensity when the type stores a colour channel.
+    const fn unit(self) -> f64 {
+        match self {
+            Self::I8 => i8::MAX as f64,
+            Self::U8 => u8::MAX as f64,
+            Self::I16 => i16::MAX as f64,
+            Self::U16 => u16::MAX as f64,
+            Self::I32 => i32::MAX as f64,
+            Self::U32 => u32::MAX as f64,
+            Self::F32 | Self::F64 => 1.0,
+        }
+    }
+}
+
+impl Body<'_> {
+    // Every row takes at least a byte, so the remaining data bounds how many a header count can really mean.
+    fn capacity(&self, count: usize) -> usize {
+        count.min(self.bytes.len() - self.position)
+    }
+
+    fn read(&mut self, kind: Scalar) -> Result<f64, MeshLoadError> {
+        let little_endian = match self.format {
+            Format::Ascii => return self.read_token(),
+            Format::BinaryLittleEndian => true,
+            Format::BinaryBigEndian => false,
+        };
+
+        let end = self.position + kind.size();
+        let bytes = self
+            .bytes
+            .get(self.position..end)
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        self.position = end;
+
+        macro_rules! decode {
+            ($ty:ty) => {{
+                let bytes = bytes.try_into().expect("slice has the size of the type");
+                f64::from(if little_endian {
+                    <$ty>::from_le_bytes(bytes)
+                } else {
+                    <$ty>::from_be_bytes(bytes)
+                })
+            }};
+        }
+
+        Ok(match kind {
+            Scalar::I8 => decode!(i8),
+            Scalar::U8 => decode!(u8),
+            Scalar::I16 => decode!(i16),
+            Scalar::U16 => decode!(u16),
+            Scalar::I32 => decode!(i32),
+            Scalar::U32 => decode!(u32),
+            Scalar::F32 => decode!(f32),
+            Scalar::F64 => decode!(f64),
+        })
+    }
+
+    fn skip(&mut self, property: &Property) -> Result<(), MeshLoadError> {
+        match property {
+            Property::Scalar { kind, .. } => {
+                self.read(*kind)?;
+            }
+            Property::List { count, item, .. } => {
+                for _ in 0..self.read_count(*count)? {
+                    self.read(*item)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_count(&mut self, kind: Scalar) -> Result<usize, MeshLoadError> {
+        let value = self.read(kind)?;
+
+        if value < 0.0 || value.fract() != 0.0 {
+            return Err(invalid(&format!("invalid count or index {value}")));
+        }
+
+        Ok(value as usize)
+    }
+
+    fn read_token(&mut self) -> Result<f64, MeshLoadError> {
+        let rest = &self.bytes[self.position..];
+        let start = rest
+            .iter()
+            .position(|byte| !byte.is_ascii_whitespace())
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let length = rest[start..]
+            .iter()
+            .position(u8::is_ascii_whitespace)
+            .unwrap_or(rest.len() - start);
+
+        let token = &rest[start..start + length];
+        self.position += start + length;
+
+        std::str::from_utf8(token)
+            .ok()
+            .and_then(|token| token.parse().ok())
+            .ok_or_else(|| invalid(&format!("invalid number '{}'", String::from_utf8_lossy(token))))
+    }
+}
+
+impl VertexLayout {
+    fn new(element: &Element) -> Result<Self, MeshLoadError> {
+        let column = |names: &[&str]| {
+            element.properties.iter().position(
+                |property| matches!(property, Property::Scalar { name, .. } if names.contains(&name.as_str())),
+            )
+        };
+
+        let position = [["x"], ["y"], ["z"]].map(|names| column(&names));
+        let [Some(x), Some(y), Some(z)] = position else {
+            return Err(invalid("vertex element is missing x, y or z"));
+        };
+
+        let normal = [["nx"], ["ny"], ["nz"]].map(|names| column(&names));
+        let uv = [["u", "s", "texture_u"], ["v", "t", "texture_v"]].map(|names| column(&names));
+        let colour = [
+            ["red", "diffuse_red"],
+            ["green", "diffuse_green"],
+            ["blue", "diffuse_blue"],
+        ]
+        .map(|names| column(&names));
+
+        let colour = match colour {
+            [Some(red), Some(green), Some(blue)] => match element.properties[red] {
+                Property::Scalar { kind, .. } => Some(([red, green, blue], kind)),
+                Property::List { .. } => None,
+            },
+            _ => None,
+        };
+        let normal = match normal {
+            [Some(nx), Some(ny), Some(nz)] => Some([nx, ny, nz]),
+            _ => None,
+        };
+        let uv = match uv {
+            [Some(u), Some(v)] => Some([u, v]),
+            _ => None,
+        };
+
+        let used = [x, y, z]
+            .into_iter()
+            .chain(normal.into_iter().flatten())
+            .chain(uv.into_iter().flatten())
+            .chain(colour.into_iter().flat_map(|(columns, _)| columns))
+            .collect::<Vec<_>>();
+        let extra = element
+            .properties
+            .iter()
+            .enumerate()
+            .filter(|(column, _)| !used.contains(column))
+            .filter_map(|(column, property)| match property {
+                Property::Scalar { name, .. } => Some((name.clone(), column)),
+                Property::List { .. } => None,
+            })
+            .collect();
+
+        Ok(Self {
+            position: [x, y, z],
+            normal,
+            uv,
+            colour,
+            extra,
+        })
+    }
+}
+
+impl Vertices {
+    fn into_buffers(self, triangles: Vec<[u32; 3]>) -> MeshBuffers {
+        // Attributes the header never declared are read as empty arrays.
+        fn present<T>(values: Vec<T>) -> Option<Vec<T>> {
+            (!values.is_empty()).then_some(values)
+        }
+
+        MeshBuffers::new(self.positions, triangles)
+            .with_normals(present(self.normals))
+            .with_uvs(present(self.uvs))
+            .with_colours(present(self.colours))
+            .with_attributes(
+                self.attributes
+                    .into_iter()
+                    .map(|(name, values)| (name, VertexAttribute::new(1, values)))
+                    .collect(),
+            )
+    }
+}
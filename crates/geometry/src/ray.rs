@@ -6,13 +6,26 @@ use crate::config::RAY_BIAS;
 pub struct Ray {
     pub origin: Point3<f32>,
     pub direction: Unit<Vector3<f32>>,
+    pub time: f32,
 }
 
 impl Ray {
     #[must_use]
     #[inline]
     pub const fn new(origin: Point3<f32>, direction: Unit<Vector3<f32>>) -> Self {
-        Self { origin, direction }
+        Self {
+            origin,
+            direction,
+            time: 0.0,
+        }
+    }
+
+    // Time within the shutter interval, from 0 at the start of object motion to 1 at its end.
+    #[must_use]
+    #[inline]
+    pub const fn with_time(mut self, time: f32) -> Self {
+        self.time = time;
+        self
     }
 
     #[must_use]
@@ -33,6 +46,7 @@ impl Ray {
         Self {
             origin: transform.transform_point(&self.origin),
             direction: Unit::new_normalize(transform.transform_vector(&self.direction)),
+            time: self.time,
         }
     }
 }
@@ -0,0 +1,8 @@
+#[allow(clippy::module_inception)]
+mod sdf;
+mod sdf_node;
+mod sdf_primitive;
+
+pub use sdf::Sdf;
+pub use sdf_node::SdfNode;
+pub use sdf_primitive::SdfPrimitive;
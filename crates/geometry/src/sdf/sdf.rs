@@ -0,0 +1,205 @@
+use std::f32::consts::{PI, TAU};
+
+use nalgebra::{Point2, Point3, Unit, Vector3};
+use rand::{Rng, RngExt, SeedableRng, rngs::SmallRng};
+
+use crate::{
+    aabb::Aabb, bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, errors::SdfError, ray::Ray,
+    sample::Sample, sampleable::Sampleable, sdf::sdf_node::SdfNode, traceable::Traceable,
+};
+
+const SDF_MAX_STEPS: usize = 512;
+const SDF_HIT_EPSILON: f32 = 1.0e-4;
+const SDF_NORMAL_EPSILON: f32 = 1.0e-3;
+const SDF_BOUNDS_MARGIN: f32 = 1.0e-3;
+const SURFACE_SAMPLES: usize = 1024;
+const SURFACE_ATTEMPTS: usize = 1 << 20;
+const SURFACE_SHELL: f32 = 1.0e-2;
+const SURFACE_PROJECTION_STEPS: usize = 4;
+
+pub struct Sdf {
+    root: SdfNode,
+    bounds: Aabb,
+    lipschitz: f32,
+    area: f32,
+    shell: f32,
+}
+
+impl Sdf {
+    pub fn new(root: SdfNode) -> Result<Self, SdfError> {
+        let bounds = root.bounds().ok_or(SdfError::Unbounded)?;
+
+        // A small margin keeps rays entering the box from starting on surfaces that touch it.
+        let margin = Vector3::repeat(SDF_BOUNDS_MARGIN);
+        let bounds = Aabb::new(bounds.min - margin, bounds.max + margin);
+        let lipschitz = root.lipschitz(&bounds);
+
+        let mut sdf = Self {
+            root,
+            bounds,
+            lipschitz,
+            area: 0.0,
+            shell: SURFACE_SHELL * (bounds.max - bounds.min).norm(),
+        };
+        sdf.estimate_area()?;
+
+        Ok(sdf)
+    }
+
+    // There is no closed form for the area of an arbitrary field, so a thin shell around the surface is sampled
+    // uniformly within the bounds and the fraction of the box it fills estimates the area.
+    fn estimate_area(&mut self) -> Result<(), SdfError> {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let extent = self.bounds.max - self.bounds.min;
+
+        let mut hits = 0;
+        let mut attempts = 0;
+        while hits < SURFACE_SAMPLES && attempts < SURFACE_ATTEMPTS {
+            attempts += 1;
+
+            if self.root.distance(self.random_point(&mut rng)).abs() < self.shell {
+                hits += 1;
+            }
+        }
+
+        if hits == 0 {
+            return Err(SdfError::NoSurface);
+        }
+
+        let volume = extent.x * extent.y * extent.z;
+        self.area = volume * hits as f32 / attempts as f32 / (2.0 * self.shell);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn random_point<R: Rng>(&self, rng: &mut R) -> Point3<f32> {
+        let extent = self.bounds.max - self.bounds.min;
+        self.bounds.min + extent.component_mul(&Vector3::from_fn(|_, _| rng.random::<f32>()))
+    }
+
+    // Walks a point down the gradient until it sits on the surface, or as close as a few steps allow.
+    #[inline]
+    fn project(&self, mut point: Point3<f32>) -> Point3<f32> {
+        for _ in 0..SURFACE_PROJECTION_STEPS {
+            let distance = self.root.distance(point);
+            if distance.abs() <= SDF_HIT_EPSILON {
+                break;
+            }
+            point -= *self.normal(point) * distance;
+        }
+
+        point
+    }
+
+    fn distance_unchecked(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
+        let (near, far) = self.bounds.ray_interval(ray)?;
+
+        let mut t = near.max(MIN_RAY_DISTANCE);
+        let end = far.min(max_distance);
+
+        // Rays leaving a surface start within the hit tolerance, so only surfaces reached after clearing it count.
+        let mut clear = near > MIN_RAY_DISTANCE;
+
+        for _ in 0..SDF_MAX_STEPS {
+            if t >= end {
+                return None;
+            }
+
+            let distance = self.root.distance(ray.origin + *ray.direction * t).abs();
+
+            if distance <= SDF_HIT_EPSILON {
+                if clear {
+                    return Some(t);
+                }
+            } else {
+                clear = true;
+            }
+
+            t += (distance / self.lipschitz).max(SDF_HIT_EPSILON * 0.5);
+        }
+
+        None
+    }
+
+    #[inline]
+    fn normal(&self, p: Point3<f32>) -> Unit<Vector3<f32>> {
+        let e = SDF_NORMAL_EPSILON;
+
+        let gradient = Vector3::from_fn(|axis, _| {
+            let offset = Vector3::ith(axis, e);
+            self.root.distance(p + offset) - self.root.distance(p - offset)
+        });
+
+        Unit::new_normalize(gradient)
+    }
+
+    #[inline]
+    fn uv(&self, position: Point3<f32>) -> Point2<f32> {
+        let local = (position - self.bounds.centroid()).normalize();
+
+        Point2::new(
+            (local.y.atan2(local.x) + PI) / TAU,
+            local.z.clamp(-1.0, 1.0).acos() / PI,
+        )
+    }
+}
+
+impl Bounded for Sdf {
+    #[inline]
+    fn bounds(&self) -> Aabb {
+        self.bounds
+    }
+}
+
+impl Traceable for Sdf {
+    #[inline]
+    fn hit(&self, ray: &Ray, max_distance: f32) -> bool {
+        self.distance(ray, max_distance).is_some()
+    }
+
+    #[inline]
+    fn distance(&self, ray: &Ray, max_distance: f32) -> Option<f32> {
+        self.distance_unchecked(ray, max_distance)
+            .filter(|distance| *distance > MIN_RAY_DISTANCE && *distance < max_distance)
+    }
+
+    #[inline]
+    fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact> {
+        let distance = self.distance(ray, max_distance)?;
+        let position = ray.origin + *ray.direction * distance;
+
+        let mut normal = self.normal(position);
+
+        if normal.dot(&ray.direction) > 0.0 {
+            normal = -normal;
+        }
+
+        Some(Contact::new(distance, position, normal, self.uv(position), None))
+    }
+}
+
+impl Sampleable for Sdf {
+    #[inline]
+    fn area(&self) -> f32 {
+        self.area
+    }
+
+    #[inline]
+    fn sample<R: Rng>(&self, rng: &mut R) -> Sample {
+        // Points uniform within the thin shell project to points uniform over the surface; construction found
+        // the shell, so rejection always terminates.
+        let position = loop {
+            let point = self.random_point(rng);
+            if self.root.distance(point).abs() < self.shell {
+                break self.project(point);
+            }
+        };
+
+        Sample {
+            position,
+            normal: self.normal(position),
+            pdf_area: 1.0 / self.area,
+        }
+    }
+}
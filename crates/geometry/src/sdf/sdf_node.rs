@@ -0,0 +1,195 @@
+use nalgebra::{Point3, Vector3};
+
+use crate::{aabb::Aabb, sdf::sdf_primitive::SdfPrimitive};
+
+pub enum SdfNode {
+    Primitive(SdfPrimitive),
+    Union(Vec<Self>),
+    Intersection(Vec<Self>),
+    Difference(Box<Self>, Box<Self>),
+    SmoothUnion {
+        blend: f32,
+        children: Vec<Self>,
+    },
+    SmoothIntersection {
+        blend: f32,
+        children: Vec<Self>,
+    },
+    SmoothDifference {
+        blend: f32,
+        a: Box<Self>,
+        b: Box<Self>,
+    },
+    // Copies the child on a grid, `limit` cells either side of the origin along each axis.
+    Repeat {
+        spacing: Vector3<f32>,
+        limit: Vector3<f32>,
+        child: Box<Self>,
+    },
+    // Rotates the child about the z axis by `rate` radians per unit of height.
+    Twist {
+        rate: f32,
+        child: Box<Self>,
+    },
+    Displace {
+        amplitude: f32,
+        frequency: f32,
+        child: Box<Self>,
+    },
+}
+
+impl SdfNode {
+    #[must_use]
+    pub fn distance(&self, p: Point3<f32>) -> f32 {
+        match self {
+            Self::Primitive(primitive) => primitive.distance(p),
+            Self::Union(children) => children
+                .iter()
+                .map(|child| child.distance(p))
+                .fold(f32::INFINITY, f32::min),
+            Self::Intersection(children) => children
+                .iter()
+                .map(|child| child.distance(p))
+                .fold(f32::NEG_INFINITY, f32::max),
+            Self::Difference(a, b) => a.distance(p).max(-b.distance(p)),
+            Self::SmoothUnion { blend, children } => children
+                .iter()
+                .map(|child| child.distance(p))
+                .reduce(|a, b| smooth_min(a, b, *blend))
+                .unwrap_or(f32::INFINITY),
+            Self::SmoothIntersection { blend, children } => children
+                .iter()
+                .map(|child| child.distance(p))
+                .reduce(|a, b| -smooth_min(-a, -b, *blend))
+                .unwrap_or(f32::NEG_INFINITY),
+            Self::SmoothDifference { blend, a, b } => -smooth_min(-a.distance(p), b.distance(p), *blend),
+            Self::Repeat { spacing, limit, child } => {
+                let cell = p.coords.component_div(spacing).map(f32::round);
+                let cell = cell.sup(&-limit).inf(limit);
+
+                child.distance(p - spacing.component_mul(&cell))
+            }
+            Self::Twist { rate, child } => {
+                let (sin, cos) = (-rate * p.z).sin_cos();
+                let twisted = Point3::new(cos.mul_add(p.x, -sin * p.y), sin.mul_add(p.x, cos * p.y), p.z);
+
+                child.distance(twisted)
+            }
+            Self::Displace {
+                amplitude,
+                frequency,
+                child,
+            } => {
+                let wave = (p.coords * *frequency).map(f32::sin);
+                amplitude.mul_add(wave.x * wave.y * wave.z, child.distance(p))
+            }
+        }
+    }
+
+    // None marks an unbounded node, such as a bare plane.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Aabb> {
+        match self {
+            Self::Primitive(primitive) => primitive.bounds(),
+            Self::Union(children) => union(children),
+            Self::SmoothUnion { blend, children } => union(children).map(|bounds| expand(bounds, blend * 0.25)),
+            Self::Intersection(children) | Self::SmoothIntersection { children, .. } => {
+                children.iter().filter_map(Self::bounds).reduce(|a, b| {
+                    let min = a.min.sup(&b.min);
+                    Aabb::new(min, a.max.inf(&b.max).sup(&min))
+                })
+            }
+            Self::Difference(a, _) | Self::SmoothDifference { a, .. } => a.bounds(),
+            Self::Repeat { spacing, limit, child } => child.bounds().map(|bounds| {
+                let reach = spacing.abs().component_mul(limit);
+                Aabb::new(bounds.min - reach, bounds.max + reach)
+            }),
+            Self::Twist { child, .. } => child.bounds().map(|bounds| {
+                let radius = radial_extent(&bounds);
+                Aabb::new(
+                    Point3::new(-radius, -radius, bounds.min.z),
+                    Point3::new(radius, radius, bounds.max.z),
+                )
+            }),
+            Self::Displace { amplitude, child, .. } => child.bounds().map(|bounds| expand(bounds, amplitude.abs())),
+        }
+    }
+
+    // Bounds how quickly the field can change within `domain`, so sphere tracing can scale its steps to stay
+    // conservative under twisting and displacement.
+    #[must_use]
+    pub fn lipschitz(&self, domain: &Aabb) -> f32 {
+        match self {
+            Self::Primitive(_) => 1.0,
+            Self::Union(children)
+            | Self::Intersection(children)
+            | Self::SmoothUnion { children, .. }
+            | Self::SmoothIntersection { children, .. } => {
+                children.iter().map(|child| child.lipschitz(domain)).fold(1.0, f32::max)
+            }
+            Self::Difference(a, b) | Self::SmoothDifference { a, b, .. } => {
+                a.lipschitz(domain).max(b.lipschitz(domain))
+            }
+            Self::Repeat { spacing, limit, child } => {
+                let reach = spacing.abs().component_mul(limit);
+                child.lipschitz(&Aabb::new(domain.min - reach, domain.max + reach))
+            }
+            Self::Twist { rate, child } => {
+                let radius = radial_extent(domain);
+                let twisted = Aabb::new(
+                    Point3::new(-radius, -radius, domain.min.z),
+                    Point3::new(radius, radius, domain.max.z),
+                );
+
+                child.lipschitz(&twisted) * (rate * radius).hypot(1.0)
+            }
+            Self::Displace {
+                amplitude,
+                frequency,
+                child,
+            } => (amplitude * frequency)
+                .abs()
+                .mul_add(3.0f32.sqrt(), child.lipschitz(domain)),
+        }
+    }
+}
+
+// Polynomial smooth minimum, which undershoots the true minimum by at most a quarter of the blend distance.
+fn smooth_min(a: f32, b: f32, blend: f32) -> f32 {
+    if blend <= 0.0 {
+        return a.min(b);
+    }
+
+    let h = (blend - (a - b).abs()).max(0.0) / blend;
+    (h * h * blend).mul_add(-0.25, a.min(b))
+}
+
+fn union(children: &[SdfNode]) -> Option<Aabb> {
+    children
+        .iter()
+        .map(SdfNode::bounds)
+        .collect::<Option<Vec<_>>>()
+        .filter(|bounds| !bounds.is_empty())
+        .map(|bounds| Aabb::union(bounds.into_iter()))
+}
+
+fn expand(bounds: Aabb, margin: f32) -> Aabb {
+    let margin = Vector3::repeat(margin);
+    Aabb::new(bounds.min - margin, bounds.max + margin)
+}
+
+fn radial_extent(bounds: &Aabb) -> f32 {
+    bounds
+        .min
+        .x
+        .abs()
+        .max(bounds.max.x.abs())
+        .hypot(bounds.min.y.abs().max(bounds.max.y.abs()))
+}
+
+impl From<SdfPrimitive> for SdfNode {
+    #[inline]
+    fn from(val: SdfPrimitive) -> Self {
+        Self::Primitive(val)
+    }
+}
@@ -0,0 +1,115 @@
+use nalgebra::{Point3, Unit, Vector3};
+
+use crate::aabb::Aabb;
+
+pub enum SdfPrimitive {
+    Sphere {
+        centre: Point3<f32>,
+        radius: f32,
+    },
+    Box {
+        centre: Point3<f32>,
+        half_size: Vector3<f32>,
+    },
+    RoundedBox {
+        centre: Point3<f32>,
+        half_size: Vector3<f32>,
+        radius: f32,
+    },
+    // Lies in the xz plane around the y axis, matching the analytic torus.
+    Torus {
+        centre: Point3<f32>,
+        major_radius: f32,
+        minor_radius: f32,
+    },
+    Cylinder {
+        a: Point3<f32>,
+        b: Point3<f32>,
+        radius: f32,
+    },
+    // The half space below the plane, which is unbounded on its own.
+    Plane {
+        normal: Unit<Vector3<f32>>,
+        offset: f32,
+    },
+}
+
+impl SdfPrimitive {
+    #[must_use]
+    pub fn distance(&self, p: Point3<f32>) -> f32 {
+        match self {
+            Self::Sphere { centre, radius } => (p - centre).norm() - radius,
+            Self::Box { centre, half_size } => box_distance(p - centre, *half_size),
+            Self::RoundedBox {
+                centre,
+                half_size,
+                radius,
+            } => box_distance(p - centre, half_size.add_scalar(-radius)) - radius,
+            Self::Torus {
+                centre,
+                major_radius,
+                minor_radius,
+            } => {
+                let local = p - centre;
+                (local.x.hypot(local.z) - major_radius).hypot(local.y) - minor_radius
+            }
+            Self::Cylinder { a, b, radius } => {
+                let ba = b - a;
+                let pa = p - a;
+                let baba = ba.dot(&ba);
+                let paba = pa.dot(&ba);
+
+                // Distances are scaled by |ba|^2 until the end to avoid normalising the axis.
+                let x = (pa * baba - ba * paba).norm() - radius * baba;
+                let y = baba.mul_add(-0.5, (baba.mul_add(-0.5, paba)).abs());
+                let x2 = x * x;
+                let y2 = y * y * baba;
+
+                let d = if x.max(y) < 0.0 {
+                    -x2.min(y2)
+                } else {
+                    (if x > 0.0 { x2 } else { 0.0 }) + (if y > 0.0 { y2 } else { 0.0 })
+                };
+
+                d.signum() * d.abs().sqrt() / baba
+            }
+            Self::Plane { normal, offset } => p.coords.dot(normal) - offset,
+        }
+    }
+
+    #[must_use]
+    pub fn bounds(&self) -> Option<Aabb> {
+        match self {
+            Self::Sphere { centre, radius } => {
+                let extent = Vector3::repeat(*radius);
+                Some(Aabb::new(centre - extent, centre + extent))
+            }
+            Self::Box { centre, half_size } | Self::RoundedBox { centre, half_size, .. } => {
+                Some(Aabb::new(centre - half_size, centre + half_size))
+            }
+            Self::Torus {
+                centre,
+                major_radius,
+                minor_radius,
+            } => {
+                let outer = major_radius + minor_radius;
+                let extent = Vector3::new(outer, *minor_radius, outer);
+                Some(Aabb::new(centre - extent, centre + extent))
+            }
+            Self::Cylinder { a, b, radius } => {
+                let axis = (b - a).normalize().abs();
+                let extent = axis.map(|n| radius * n.mul_add(-n, 1.0).sqrt());
+
+                Some(Aabb::union(
+                    [a, b].into_iter().map(|end| Aabb::new(end - extent, end + extent)),
+                ))
+            }
+            Self::Plane { .. } => None,
+        }
+    }
+}
+
+fn box_distance(local: Vector3<f32>, half_size: Vector3<f32>) -> f32 {
+    let q = local.abs() - half_size;
+    q.sup(&Vector3::zeros()).norm() + q.max().min(0.0)
+}
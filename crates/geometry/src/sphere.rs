@@ -4,8 +4,8 @@ use nalgebra::{Point2, Point3, Unit, Vector3};
 use rand::{Rng, RngExt};
 
 use crate::{
-    aabb::Aabb, bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, ray::Ray, sample::Sample,
-    sampleable::Sampleable, traceable::Traceable,
+    aabb::Aabb, bounded::Bounded, config::MIN_RAY_DISTANCE, contact::Contact, crossings::convex_crossings, ray::Ray,
+    sample::Sample, sampleable::Sampleable, traceable::Traceable,
 };
 
 pub struct Sphere {
@@ -71,6 +71,11 @@ impl Traceable for Sphere {
 
         Some(Contact::new(distance, position, normal, Point2::new(u, v), None))
     }
+
+    #[inline]
+    fn crossings(&self, ray: &Ray) -> Option<Vec<Contact>> {
+        Some(convex_crossings(self, ray))
+    }
 }
 
 impl Sampleable for Sphere {
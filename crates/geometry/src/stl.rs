@@ -0,0 +1,80 @@
+use std::{fs::read, path::Path};
+
+use nalgebra::Point3;
+
+use crate::{errors::MeshLoadError, mesh_buffers::MeshBuffers};
+
+const HEADER_BYTES: usize = 80;
+const FACET_BYTES: usize = 50;
+
+// Reads binary and ASCII STL, keeping only vertex positions as facet normals are often unreliable.
+// Facets repeat every shared corner, so identical positions are welded back together.
+pub fn load(path: &Path) -> Result<MeshBuffers, MeshLoadError> {
+    let bytes = read(path)?;
+
+    // Binary files may also begin with "solid", so the facet count is checked against the file size first.
+    if let Some(count) = bytes.get(HEADER_BYTES..HEADER_BYTES + 4) {
+        let count = u32::from_le_bytes(count.try_into().expect("slice is four bytes")) as usize;
+
+        if bytes.len() == HEADER_BYTES + 4 + count * FACET_BYTES {
+            return Ok(load_binary(&bytes[HEADER_BYTES + 4..]));
+        }
+    }
+
+    if bytes.starts_with(b"solid") {
+        return load_ascii(&String::from_utf8_lossy(&bytes));
+    }
+
+    Err(invalid("file is neither binary nor ASCII STL"))
+}
+
+fn invalid(reason: &str) -> MeshLoadError {
+    MeshLoadError::Stl {
+        reason: reason.to_owned(),
+    }
+}
+
+fn load_binary(facets: &[u8]) -> MeshBuffers {
+    MeshBuffers::from_positions(facets.chunks_exact(FACET_BYTES).map(|facet| {
+        // Each facet holds a normal, three vertices and a two byte attribute count.
+        let value = |index: usize| {
+            let start = (3 + index) * 4;
+            f32::from_le_bytes(facet[start..start + 4].try_into().expect("slice is four bytes"))
+        };
+
+        [0, 1, 2].map(|vertex| Point3::new(value(vertex * 3), value(vertex * 3 + 1), value(vertex * 3 + 2)))
+    }))
+}
+
+fn load_ascii(text: &str) -> Result<MeshBuffers, MeshLoadError> {
+    let mut facets = Vec::new();
+    let mut vertices = Vec::with_capacity(3);
+
+    for line in text.lines() {
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("vertex") => {
+                let coords = words
+                    .map(str::parse::<f32>)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| invalid(&format!("invalid vertex '{}'", line.trim())))?;
+
+                let [x, y, z] = coords[..] else {
+                    return Err(invalid(&format!("vertex needs three coordinates '{}'", line.trim())));
+                };
+                vertices.push(Point3::new(x, y, z));
+            }
+            Some("endfacet") => {
+                let [a, b, c] = vertices[..] else {
+                    return Err(invalid(&format!("facet has {} vertices", vertices.len())));
+                };
+                facets.push([a, b, c]);
+                vertices.clear();
+            }
+            _ => {}
+        }
+    }
+
+    Ok(MeshBuffers::from_positions(facets))
+}
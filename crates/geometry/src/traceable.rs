@@ -9,4 +9,11 @@ pub trait Traceable {
 
     #[must_use]
     fn intersection(&self, ray: &Ray, max_distance: f32) -> Option<Contact>;
+
+    // Every crossing of the surface along the whole line of the ray, including behind its origin, ordered by
+    // distance so that consecutive pairs enclose the solid. Surfaces that do not bound a solid report none.
+    #[must_use]
+    fn crossings(&self, _ray: &Ray) -> Option<Vec<Contact>> {
+        None
+    }
 }
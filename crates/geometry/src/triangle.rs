@@ -1,3 +1,4 @@
+use antler_colour::Rgb;
 use nalgebra::{Point2, Point3, Unit, Vector3};
 use rand::{Rng, RngExt};
 
@@ -13,6 +14,7 @@ pub struct Triangle {
     vertices: [Point3<f32>; 3],
     normals: Option<[Unit<Vector3<f32>>; 3]>,
     uvs: Option<[Point2<f32>; 3]>,
+    colours: Option<[Rgb; 3]>,
 }
 
 impl Triangle {
@@ -22,7 +24,19 @@ impl Triangle {
         normals: Option<[Unit<Vector3<f32>>; 3]>,
         uvs: Option<[Point2<f32>; 3]>,
     ) -> Self {
-        Self { vertices, normals, uvs }
+        Self {
+            vertices,
+            normals,
+            uvs,
+            colours: None,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn with_colours(mut self, colours: Option<[Rgb; 3]>) -> Self {
+        self.colours = colours;
+        self
     }
 
     #[must_use]
@@ -105,6 +119,11 @@ impl Triangle {
             None => Point2::new(bary.y, bary.z),
         }
     }
+
+    #[inline]
+    fn interpolate_colour(&self, bary: Vector3<f32>) -> Option<Rgb> {
+        self.colours.map(|[ca, cb, cc]| ca * bary.x + cb * bary.y + cc * bary.z)
+    }
 }
 
 impl Bounded for Triangle {
@@ -158,13 +177,10 @@ impl Traceable for Triangle {
             normal = -normal;
         }
 
-        Some(Contact::new(
-            distance,
-            position,
-            normal,
-            self.interpolate_uv(bary),
-            Some(bary),
-        ))
+        Some(
+            Contact::new(distance, position, normal, self.interpolate_uv(bary), Some(bary))
+                .with_colour(self.interpolate_colour(bary)),
+        )
     }
 }
 
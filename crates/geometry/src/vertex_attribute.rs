@@ -0,0 +1,48 @@
+// A named per-vertex channel beyond the built-in ones, stored as a fixed number of floats per vertex.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VertexAttribute {
+    pub components: usize,
+    pub values: Vec<f32>,
+}
+
+impl VertexAttribute {
+    #[must_use]
+    #[inline]
+    pub const fn new(components: usize, values: Vec<f32>) -> Self {
+        Self { components, values }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len() / self.components.max(1)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self, vertex: usize) -> &[f32] {
+        &self.values[vertex * self.components..(vertex + 1) * self.components]
+    }
+
+    #[must_use]
+    pub(crate) fn zeros(components: usize, count: usize) -> Self {
+        Self::new(components, vec![0.0; components * count])
+    }
+
+    #[must_use]
+    pub(crate) fn gather(&self, kept: &[u32]) -> Self {
+        Self::new(
+            self.components,
+            kept.iter()
+                .flat_map(|&index| self.get(index as usize))
+                .copied()
+                .collect(),
+        )
+    }
+}
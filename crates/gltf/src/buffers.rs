@@ -0,0 +1,243 @@
+use std::{fs::read, path::Path};
+
+use crate::{base64, document::Document, errors::GltfError};
+
+const BASE64_MARKER: &str = ";base64,";
+
+#[derive(Debug, Clone, Copy)]
+enum Component {
+    I8,
+    U8,
+    I16,
+    U16,
+    U32,
+    F32,
+}
+
+struct AccessorView<'a> {
+    bytes: &'a [u8],
+    stride: usize,
+    count: usize,
+    component: Component,
+    components: usize,
+    normalized: bool,
+}
+
+// The raw bytes behind every accessor, with the document that describes how to read them.
+pub struct Buffers<'a> {
+    document: &'a Document,
+    data: Vec<Vec<u8>>,
+}
+
+impl<'a> Buffers<'a> {
+    // Buffers are embedded as data URIs, stored beside the file, or held in a GLB's binary chunk.
+    pub fn load(document: &'a Document, directory: &Path, mut binary: Option<Vec<u8>>) -> Result<Self, GltfError> {
+        let data = document
+            .buffers
+            .iter()
+            .enumerate()
+            .map(|(index, buffer)| {
+                let bytes = match &buffer.uri {
+                    Some(uri) => read_uri(uri, directory)?,
+                    None => binary
+                        .take()
+                        .filter(|_| index == 0)
+                        .ok_or_else(|| invalid(&format!("buffer {index} has no data")))?,
+                };
+
+                if bytes.len() < buffer.byte_length {
+                    return Err(invalid(&format!("buffer {index} is shorter than its byteLength")));
+                }
+
+                Ok(bytes)
+            })
+            .collect::<Result<_, GltfError>>()?;
+
+        Ok(Self { document, data })
+    }
+
+    pub fn view(&self, index: usize) -> Result<&[u8], GltfError> {
+        let view = self
+            .document
+            .buffer_views
+            .get(index)
+            .ok_or_else(|| invalid(&format!("missing buffer view {index}")))?;
+        let buffer = self
+            .data
+            .get(view.buffer)
+            .ok_or_else(|| invalid(&format!("buffer view {index} refers to missing buffer {}", view.buffer)))?;
+
+        buffer
+            .get(view.byte_offset..view.byte_offset + view.byte_length)
+            .ok_or_else(|| invalid(&format!("buffer view {index} overruns its buffer")))
+    }
+
+    fn accessor(&self, index: usize) -> Result<AccessorView<'_>, GltfError> {
+        let accessor = self
+            .document
+            .accessors
+            .get(index)
+            .ok_or_else(|| invalid(&format!("missing accessor {index}")))?;
+
+        if accessor.sparse.is_some() {
+            return Err(unsupported("sparse accessors"));
+        }
+
+        let component = match accessor.component_type {
+            5120 => Component::I8,
+            5121 => Component::U8,
+            5122 => Component::I16,
+            5123 => Component::U16,
+            5125 => Component::U32,
+            5126 => Component::F32,
+            other => return Err(invalid(&format!("accessor {index} has component type {other}"))),
+        };
+        let components = match accessor.kind.as_str() {
+            "SCALAR" => 1,
+            "VEC2" => 2,
+            "VEC3" => 3,
+            "VEC4" => 4,
+            other => return Err(unsupported(&format!("{other} accessors"))),
+        };
+
+        let view_index = accessor
+            .buffer_view
+            .ok_or_else(|| unsupported("accessors without a buffer view"))?;
+        let view = self.view(view_index)?;
+        let element = component.size() * components;
+        let stride = self.document.buffer_views[view_index].byte_stride.unwrap_or(element);
+
+        let length = accessor.count.checked_sub(1).map_or(0, |last| last * stride + element);
+        let bytes = view
+            .get(accessor.byte_offset..accessor.byte_offset + length)
+            .ok_or_else(|| invalid(&format!("accessor {index} overruns its buffer view")))?;
+
+        Ok(AccessorView {
+            bytes,
+            stride,
+            count: accessor.count,
+            component,
+            components,
+            normalized: accessor.normalized,
+        })
+    }
+
+    // Values are returned flat, along with the number of components in each element.
+    pub fn floats(&self, index: usize) -> Result<(Vec<f32>, usize), GltfError> {
+        let view = self.accessor(index)?;
+
+        let values = (0..view.count)
+            .flat_map(|element| {
+                let start = element * view.stride;
+                (0..view.components).map(move |component| {
+                    let offset = start + component * view.component.size();
+                    view.component.read(&view.bytes[offset..], view.normalized)
+                })
+            })
+            .collect();
+
+        Ok((values, view.components))
+    }
+
+    pub fn indices(&self, index: usize) -> Result<Vec<usize>, GltfError> {
+        let view = self.accessor(index)?;
+
+        if view.components != 1 || !matches!(view.component, Component::U8 | Component::U16 | Component::U32) {
+            return Err(invalid(&format!("accessor {index} cannot hold indices")));
+        }
+
+        Ok((0..view.count)
+            .map(|element| view.component.read_index(&view.bytes[element * view.stride..]))
+            .collect())
+    }
+}
+
+impl Component {
+    const fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::U32 | Self::F32 => 4,
+        }
+    }
+
+    // Normalised integers map onto [0, 1] when unsigned and [-1, 1] when signed.
+    fn read(self, bytes: &[u8], normalized: bool) -> f32 {
+        let value = match self {
+            Self::I8 => f32::from(bytes[0].cast_signed()),
+            Self::U8 => f32::from(bytes[0]),
+            Self::I16 => f32::from(i16::from_le_bytes([bytes[0], bytes[1]])),
+            Self::U16 => f32::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+            Self::U32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32,
+            Self::F32 => return f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        };
+
+        if !normalized {
+            return value;
+        }
+
+        match self {
+            Self::I8 => (value / f32::from(i8::MAX)).max(-1.0),
+            Self::U8 => value / f32::from(u8::MAX),
+            Self::I16 => (value / f32::from(i16::MAX)).max(-1.0),
+            Self::U16 => value / f32::from(u16::MAX),
+            Self::U32 => value / u32::MAX as f32,
+            Self::F32 => value,
+        }
+    }
+
+    fn read_index(self, bytes: &[u8]) -> usize {
+        match self {
+            Self::U8 => usize::from(bytes[0]),
+            Self::U16 => usize::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+            _ => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize,
+        }
+    }
+}
+
+pub fn read_uri(uri: &str, directory: &Path) -> Result<Vec<u8>, GltfError> {
+    if let Some(data) = uri.strip_prefix("data:") {
+        let (_, encoded) = data
+            .split_once(BASE64_MARKER)
+            .ok_or_else(|| unsupported("data URIs without base64 encoding"))?;
+        return base64::decode(encoded).ok_or_else(|| invalid("malformed base64 data URI"));
+    }
+
+    Ok(read(directory.join(percent_decode(uri)))?)
+}
+
+// Relative URIs may escape spaces and other reserved characters in file names.
+fn percent_decode(uri: &str) -> String {
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| bytes.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+pub fn invalid(reason: &str) -> GltfError {
+    GltfError::Invalid {
+        reason: reason.to_owned(),
+    }
+}
+
+pub fn unsupported(feature: &str) -> GltfError {
+    GltfError::Unsupported {
+        feature: feature.to_owned(),
+    }
+}
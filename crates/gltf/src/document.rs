@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+// The subset of the glTF 2.0 JSON schema the importer reads; anything else in the file is ignored.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    #[serde(default)]
+    pub scene: Option<usize>,
+    #[serde(default)]
+    pub scenes: Vec<Scene>,
+    #[serde(default)]
+    pub nodes: Vec<Node>,
+    #[serde(default)]
+    pub meshes: Vec<Mesh>,
+    #[serde(default)]
+    pub accessors: Vec<Accessor>,
+    #[serde(default)]
+    pub buffer_views: Vec<BufferView>,
+    #[serde(default)]
+    pub buffers: Vec<Buffer>,
+    #[serde(default)]
+    pub materials: Vec<Material>,
+    #[serde(default)]
+    pub textures: Vec<Texture>,
+    #[serde(default)]
+    pub images: Vec<Image>,
+    #[serde(default)]
+    pub cameras: Vec<Camera>,
+    #[serde(default)]
+    pub extensions: DocumentExtensions,
+    #[serde(default)]
+    pub extensions_required: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Scene {
+    #[serde(default)]
+    pub nodes: Vec<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Node {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub children: Vec<usize>,
+    #[serde(default)]
+    pub mesh: Option<usize>,
+    #[serde(default)]
+    pub camera: Option<usize>,
+    #[serde(default)]
+    pub matrix: Option<[f32; 16]>,
+    #[serde(default)]
+    pub translation: Option<[f32; 3]>,
+    #[serde(default)]
+    pub rotation: Option<[f32; 4]>,
+    #[serde(default)]
+    pub scale: Option<[f32; 3]>,
+    #[serde(default)]
+    pub extensions: NodeExtensions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct NodeExtensions {
+    #[serde(default, rename = "KHR_lights_punctual")]
+    pub lights_punctual: Option<NodeLight>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NodeLight {
+    pub light: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Mesh {
+    #[serde(default)]
+    pub primitives: Vec<Primitive>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Primitive {
+    pub attributes: BTreeMap<String, usize>,
+    #[serde(default)]
+    pub indices: Option<usize>,
+    #[serde(default)]
+    pub material: Option<usize>,
+    #[serde(default = "default_mode")]
+    pub mode: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Accessor {
+    #[serde(default)]
+    pub buffer_view: Option<usize>,
+    #[serde(default)]
+    pub byte_offset: usize,
+    pub component_type: u32,
+    #[serde(default)]
+    pub normalized: bool,
+    pub count: usize,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub sparse: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BufferView {
+    pub buffer: usize,
+    #[serde(default)]
+    pub byte_offset: usize,
+    pub byte_length: usize,
+    #[serde(default)]
+    pub byte_stride: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Buffer {
+    #[serde(default)]
+    pub uri: Option<String>,
+    pub byte_length: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Material {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub pbr_metallic_roughness: Option<PbrMetallicRoughness>,
+    #[serde(default)]
+    pub emissive_factor: Option<[f32; 3]>,
+    #[serde(default)]
+    pub extensions: MaterialExtensions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PbrMetallicRoughness {
+    #[serde(default)]
+    pub base_color_factor: Option<[f32; 4]>,
+    #[serde(default)]
+    pub base_color_texture: Option<TextureInfo>,
+    #[serde(default)]
+    pub metallic_factor: Option<f32>,
+    #[serde(default)]
+    pub roughness_factor: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextureInfo {
+    pub index: usize,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MaterialExtensions {
+    #[serde(default, rename = "KHR_materials_emissive_strength")]
+    pub emissive_strength: Option<EmissiveStrength>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmissiveStrength {
+    pub emissive_strength: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Texture {
+    #[serde(default)]
+    pub source: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Image {
+    #[serde(default)]
+    pub uri: Option<String>,
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    #[serde(default)]
+    pub buffer_view: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Camera {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub perspective: Option<PerspectiveCamera>,
+    #[serde(default)]
+    pub orthographic: Option<OrthographicCamera>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PerspectiveCamera {
+    pub yfov: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrthographicCamera {
+    pub xmag: f32,
+    pub ymag: f32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DocumentExtensions {
+    #[serde(default, rename = "KHR_lights_punctual")]
+    pub lights_punctual: Option<LightsPunctual>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LightsPunctual {
+    #[serde(default)]
+    pub lights: Vec<Light>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Light {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub color: Option<[f32; 3]>,
+    #[serde(default)]
+    pub intensity: Option<f32>,
+}
+
+const fn default_mode() -> u32 {
+    4
+}
@@ -0,0 +1,51 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result},
+    io::Error as IoError,
+};
+
+use antler_image::errors::ImageLoadError;
+use serde_json::Error as JsonError;
+
+#[derive(Debug)]
+pub enum GltfError {
+    Io(IoError),
+    Json(JsonError),
+    Image(ImageLoadError),
+    Invalid { reason: String },
+    Unsupported { feature: String },
+    UnknownNode { name: String },
+}
+
+impl From<IoError> for GltfError {
+    fn from(value: IoError) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<JsonError> for GltfError {
+    fn from(value: JsonError) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl From<ImageLoadError> for GltfError {
+    fn from(value: ImageLoadError) -> Self {
+        Self::Image(value)
+    }
+}
+
+impl Display for GltfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to read glTF: {err}"),
+            Self::Json(err) => write!(f, "Failed to parse glTF: {err}"),
+            Self::Image(err) => write!(f, "Failed to load glTF texture: {err}"),
+            Self::Invalid { reason } => write!(f, "Invalid glTF: {reason}"),
+            Self::Unsupported { feature } => write!(f, "Unsupported glTF feature: {feature}"),
+            Self::UnknownNode { name } => write!(f, "glTF has no node named '{name}'"),
+        }
+    }
+}
+
+impl Error for GltfError {}
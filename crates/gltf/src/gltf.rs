@@ -0,0 +1,441 @@
+use std::{collections::HashMap, fs::read, io::Cursor, ops::Range, path::Path};
+
+use antler_colour::Rgb;
+use antler_image::RgbImage;
+use nalgebra::{Matrix4, Quaternion, Translation3, UnitQuaternion, Vector3};
+
+use crate::{
+    buffers::{Buffers, invalid, read_uri, unsupported},
+    document::{Document, Node},
+    errors::GltfError,
+    primitive::GltfPrimitive,
+};
+
+const GLB_MAGIC: &[u8] = b"glTF";
+const GLB_VERSION: u32 = 2;
+const GLB_HEADER_SIZE: usize = 12;
+const GLB_JSON: u32 = 0x4E4F_534A;
+const GLB_BIN: u32 = 0x004E_4942;
+const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G'];
+const SUPPORTED_EXTENSIONS: [&str; 2] = ["KHR_lights_punctual", "KHR_materials_emissive_strength"];
+
+// Everything a glTF scene places, with transforms in the file's own y-up coordinates.
+#[derive(Default)]
+pub struct Gltf {
+    pub primitives: Vec<GltfPrimitive>,
+    pub materials: Vec<GltfMaterial>,
+    pub images: Vec<RgbImage>,
+    pub objects: Vec<GltfObject>,
+    pub cameras: Vec<GltfCamera>,
+    pub lights: Vec<GltfLight>,
+}
+
+// Textures are indices into the loaded images; a texture replaces the base colour rather than tinting it.
+#[derive(Clone)]
+pub struct GltfMaterial {
+    pub name: Option<String>,
+    pub base_colour: Rgb,
+    pub base_colour_texture: Option<usize>,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub emissive: Rgb,
+}
+
+pub struct GltfObject {
+    pub primitive: usize,
+    pub transform: Matrix4<f32>,
+}
+
+pub enum GltfProjection {
+    Perspective { vertical_fov: f32 },
+    Orthographic { size: [f32; 2] },
+}
+
+// Cameras look down their local -z axis with y up.
+pub struct GltfCamera {
+    pub name: String,
+    pub projection: GltfProjection,
+    pub transform: Matrix4<f32>,
+}
+
+pub enum GltfLightKind {
+    Directional,
+    Point,
+}
+
+// Lights shine down their local -z axis; spot lights are imported as points, ignoring their cone.
+pub struct GltfLight {
+    pub name: Option<String>,
+    pub kind: GltfLightKind,
+    pub colour: Rgb,
+    pub intensity: f32,
+    pub transform: Matrix4<f32>,
+}
+
+struct Loader<'a> {
+    document: &'a Document,
+    buffers: Buffers<'a>,
+    directory: &'a Path,
+    meshes: HashMap<usize, Range<usize>>,
+    images: HashMap<usize, usize>,
+    visited: Vec<bool>,
+    gltf: Gltf,
+}
+
+impl Default for GltfMaterial {
+    // Primitives without a material use the specification's default, a rough white metal.
+    fn default() -> Self {
+        Self {
+            name: None,
+            base_colour: Rgb::WHITE,
+            base_colour_texture: None,
+            metallic: 1.0,
+            roughness: 1.0,
+            emissive: Rgb::BLACK,
+        }
+    }
+}
+
+impl Gltf {
+    // Loads the default scene, or just the named node's subtree placed where its ancestors put it.
+    pub fn load(path: impl AsRef<Path>, node: Option<&str>) -> Result<Self, GltfError> {
+        let path = path.as_ref();
+        let bytes = read(path)?;
+
+        let (document, binary) = if bytes.starts_with(GLB_MAGIC) {
+            parse_glb(&bytes)?
+        } else {
+            (serde_json::from_slice(&bytes)?, None)
+        };
+
+        if let Some(extension) = document
+            .extensions_required
+            .iter()
+            .find(|extension| !SUPPORTED_EXTENSIONS.contains(&extension.as_str()))
+        {
+            return Err(unsupported(&format!("required extension {extension}")));
+        }
+
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+        let mut loader = Loader {
+            document: &document,
+            buffers: Buffers::load(&document, directory, binary)?,
+            directory,
+            meshes: HashMap::new(),
+            images: HashMap::new(),
+            visited: vec![false; document.nodes.len()],
+            gltf: Self::default(),
+        };
+
+        loader.materials()?;
+
+        for (root, transform) in loader.roots(node)? {
+            loader.visit(root, &transform)?;
+        }
+
+        Ok(loader.gltf)
+    }
+
+    #[must_use]
+    pub fn material(&self, index: Option<usize>) -> GltfMaterial {
+        index
+            .and_then(|index| self.materials.get(index))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl Loader<'_> {
+    fn materials(&mut self) -> Result<(), GltfError> {
+        for material in &self.document.materials {
+            let pbr = material.pbr_metallic_roughness.as_ref();
+
+            let [red, green, blue, _] = pbr.and_then(|pbr| pbr.base_color_factor).unwrap_or([1.0; 4]);
+            let base_colour_texture = pbr
+                .and_then(|pbr| pbr.base_color_texture.as_ref())
+                .map(|info| self.texture(info.index))
+                .transpose()?;
+
+            let strength = material
+                .extensions
+                .emissive_strength
+                .as_ref()
+                .map_or(1.0, |extension| extension.emissive_strength);
+            let [emissive_red, emissive_green, emissive_blue] = material.emissive_factor.unwrap_or([0.0; 3]);
+
+            self.gltf.materials.push(GltfMaterial {
+                name: material.name.clone(),
+                base_colour: Rgb::new(red, green, blue),
+                base_colour_texture,
+                metallic: pbr.and_then(|pbr| pbr.metallic_factor).unwrap_or(1.0),
+                roughness: pbr.and_then(|pbr| pbr.roughness_factor).unwrap_or(1.0),
+                emissive: Rgb::new(emissive_red, emissive_green, emissive_blue) * strength,
+            });
+        }
+
+        Ok(())
+    }
+
+    // Images are decoded once however many textures share them.
+    fn texture(&mut self, index: usize) -> Result<usize, GltfError> {
+        let source = self
+            .document
+            .textures
+            .get(index)
+            .ok_or_else(|| invalid(&format!("missing texture {index}")))?
+            .source
+            .ok_or_else(|| unsupported("textures without a PNG source"))?;
+
+        if let Some(&loaded) = self.images.get(&source) {
+            return Ok(loaded);
+        }
+
+        let image = self
+            .document
+            .images
+            .get(source)
+            .ok_or_else(|| invalid(&format!("missing image {source}")))?;
+        let bytes = match (&image.uri, image.buffer_view) {
+            (Some(uri), _) => read_uri(uri, self.directory)?,
+            (None, Some(view)) => self.buffers.view(view)?.to_vec(),
+            (None, None) => return Err(invalid(&format!("image {source} has no data"))),
+        };
+
+        if !bytes.starts_with(PNG_SIGNATURE) {
+            let format = image.mime_type.as_deref().unwrap_or("non-PNG");
+            return Err(unsupported(&format!("{format} textures")));
+        }
+
+        let loaded = self.gltf.images.len();
+        self.gltf.images.push(RgbImage::decode(Cursor::new(bytes))?);
+        self.images.insert(source, loaded);
+
+        Ok(loaded)
+    }
+
+    fn roots(&self, node: Option<&str>) -> Result<Vec<(usize, Matrix4<f32>)>, GltfError> {
+        let nodes = &self.document.nodes;
+
+        let mut parents = vec![None; nodes.len()];
+        for (parent, node) in nodes.iter().enumerate() {
+            for &child in &node.children {
+                if let Some(slot) = parents.get_mut(child) {
+                    *slot = Some(parent);
+                }
+            }
+        }
+
+        if let Some(name) = node {
+            let index = nodes
+                .iter()
+                .position(|node| node.name.as_deref() == Some(name))
+                .ok_or_else(|| GltfError::UnknownNode { name: name.to_owned() })?;
+
+            let mut transform = Matrix4::identity();
+            let mut ancestor = parents[index];
+            for _ in 0..nodes.len() {
+                let Some(parent) = ancestor else {
+                    return Ok(vec![(index, transform)]);
+                };
+                transform = local_transform(&nodes[parent]) * transform;
+                ancestor = parents[parent];
+            }
+
+            return Err(invalid("node hierarchy contains a cycle"));
+        }
+
+        let scene = self
+            .document
+            .scene
+            .or_else(|| (!self.document.scenes.is_empty()).then_some(0));
+        let roots = if let Some(scene) = scene {
+            self.document
+                .scenes
+                .get(scene)
+                .ok_or_else(|| invalid(&format!("missing scene {scene}")))?
+                .nodes
+                .clone()
+        } else {
+            // Files without scenes place every node that has no parent.
+            (0..nodes.len()).filter(|&index| parents[index].is_none()).collect()
+        };
+
+        Ok(roots.into_iter().map(|root| (root, Matrix4::identity())).collect())
+    }
+
+    fn visit(&mut self, index: usize, parent: &Matrix4<f32>) -> Result<(), GltfError> {
+        let document = self.document;
+        let node = document
+            .nodes
+            .get(index)
+            .ok_or_else(|| invalid(&format!("missing node {index}")))?;
+
+        if std::mem::replace(&mut self.visited[index], true) {
+            return Err(invalid(&format!("node {index} appears twice in the hierarchy")));
+        }
+
+        let transform = parent * local_transform(node);
+
+        if let Some(mesh) = node.mesh {
+            for primitive in self.mesh(mesh)? {
+                self.gltf.objects.push(GltfObject { primitive, transform });
+            }
+        }
+
+        if let Some(camera) = node.camera {
+            let camera = self.camera(camera, node, transform)?;
+            self.gltf.cameras.push(camera);
+        }
+
+        if let Some(light) = &node.extensions.lights_punctual {
+            let light = self.light(light.light, transform)?;
+            self.gltf.lights.push(light);
+        }
+
+        for &child in &node.children {
+            self.visit(child, &transform)?;
+        }
+
+        Ok(())
+    }
+
+    // Meshes are read the first time a node uses them, so every node sharing one shares its primitives.
+    fn mesh(&mut self, index: usize) -> Result<Range<usize>, GltfError> {
+        if let Some(range) = self.meshes.get(&index) {
+            return Ok(range.clone());
+        }
+
+        let mesh = self
+            .document
+            .meshes
+            .get(index)
+            .ok_or_else(|| invalid(&format!("missing mesh {index}")))?;
+
+        let start = self.gltf.primitives.len();
+        for primitive in &mesh.primitives {
+            let Some(primitive) = GltfPrimitive::read(&self.buffers, primitive)? else {
+                continue;
+            };
+
+            if let Some(material) = primitive.material
+                && material >= self.gltf.materials.len()
+            {
+                return Err(invalid(&format!("mesh {index} refers to missing material {material}")));
+            }
+
+            if !primitive.is_empty() {
+                self.gltf.primitives.push(primitive);
+            }
+        }
+
+        let range = start..self.gltf.primitives.len();
+        self.meshes.insert(index, range.clone());
+
+        Ok(range)
+    }
+
+    fn camera(&self, index: usize, node: &Node, transform: Matrix4<f32>) -> Result<GltfCamera, GltfError> {
+        let camera = self
+            .document
+            .cameras
+            .get(index)
+            .ok_or_else(|| invalid(&format!("missing camera {index}")))?;
+
+        let projection = match (&camera.perspective, &camera.orthographic) {
+            (Some(perspective), _) => GltfProjection::Perspective {
+                vertical_fov: perspective.yfov,
+            },
+            (None, Some(orthographic)) => GltfProjection::Orthographic {
+                size: [orthographic.xmag * 2.0, orthographic.ymag * 2.0],
+            },
+            (None, None) => return Err(invalid(&format!("camera {index} has no projection"))),
+        };
+
+        // The node's name is what authoring tools show, so it is preferred over the camera's.
+        let name = node
+            .name
+            .clone()
+            .or_else(|| camera.name.clone())
+            .unwrap_or_else(|| format!("camera {index}"));
+
+        Ok(GltfCamera {
+            name,
+            projection,
+            transform,
+        })
+    }
+
+    fn light(&self, index: usize, transform: Matrix4<f32>) -> Result<GltfLight, GltfError> {
+        let light = self
+            .document
+            .extensions
+            .lights_punctual
+            .as_ref()
+            .and_then(|extension| extension.lights.get(index))
+            .ok_or_else(|| invalid(&format!("missing light {index}")))?;
+
+        let kind = match light.kind.as_str() {
+            "directional" => GltfLightKind::Directional,
+            "point" | "spot" => GltfLightKind::Point,
+            other => return Err(unsupported(&format!("{other} lights"))),
+        };
+        let [red, green, blue] = light.color.unwrap_or([1.0; 3]);
+
+        Ok(GltfLight {
+            name: light.name.clone(),
+            kind,
+            colour: Rgb::new(red, green, blue),
+            intensity: light.intensity.unwrap_or(1.0),
+            transform,
+        })
+    }
+}
+
+fn local_transform(node: &Node) -> Matrix4<f32> {
+    if let Some(matrix) = &node.matrix {
+        return Matrix4::from_column_slice(matrix);
+    }
+
+    let [x, y, z] = node.translation.unwrap_or([0.0; 3]);
+    let [i, j, k, w] = node.rotation.unwrap_or([0.0, 0.0, 0.0, 1.0]);
+    let [scale_x, scale_y, scale_z] = node.scale.unwrap_or([1.0; 3]);
+
+    Translation3::new(x, y, z).to_homogeneous()
+        * UnitQuaternion::from_quaternion(Quaternion::new(w, i, j, k)).to_homogeneous()
+        * Matrix4::new_nonuniform_scaling(&Vector3::new(scale_x, scale_y, scale_z))
+}
+
+// A binary glTF is a header followed by a JSON chunk and an optional binary chunk holding the first buffer.
+fn parse_glb(bytes: &[u8]) -> Result<(Document, Option<Vec<u8>>), GltfError> {
+    let word = |offset: usize| {
+        bytes
+            .get(offset..offset + 4)
+            .map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+    };
+
+    if word(4) != Some(GLB_VERSION) {
+        return Err(unsupported("GLB versions other than 2"));
+    }
+
+    let mut document = None;
+    let mut binary = None;
+
+    let mut offset = GLB_HEADER_SIZE;
+    while let (Some(length), Some(kind)) = (word(offset), word(offset + 4)) {
+        let start = offset + 8;
+        let end = start + length as usize;
+        let chunk = bytes
+            .get(start..end)
+            .ok_or_else(|| invalid("GLB chunk overruns the file"))?;
+
+        match kind {
+            GLB_JSON if document.is_none() => document = Some(serde_json::from_slice(chunk)?),
+            GLB_BIN if binary.is_none() => binary = Some(chunk.to_vec()),
+            _ => {}
+        }
+
+        offset = end;
+    }
+
+    Ok((document.ok_or_else(|| invalid("GLB has no JSON chunk"))?, binary))
+}
@@ -0,0 +1,11 @@
+mod base64;
+mod buffers;
+mod document;
+pub mod errors;
+mod gltf;
+mod primitive;
+mod transform;
+
+pub use gltf::{Gltf, GltfCamera, GltfLight, GltfLightKind, GltfMaterial, GltfObject, GltfProjection};
+pub use primitive::GltfPrimitive;
+pub use transform::similarity;
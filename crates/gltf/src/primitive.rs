@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use antler_colour::Rgb;
+use antler_geometry::{MeshBuffers, VertexAttribute};
+use nalgebra::{Matrix4, Point2, Point3, Vector3};
+
+use crate::{
+    buffers::{Buffers, invalid},
+    document::Primitive,
+    errors::GltfError,
+};
+
+const TRIANGLES: u32 = 4;
+const TRIANGLE_STRIP: u32 = 5;
+const TRIANGLE_FAN: u32 = 6;
+const BUILT_IN_ATTRIBUTES: [&str; 4] = ["POSITION", "NORMAL", "TEXCOORD_0", "COLOR_0"];
+
+// The vertex data of one mesh primitive in its own coordinate frame.
+pub struct GltfPrimitive {
+    positions: Vec<Point3<f32>>,
+    normals: Option<Vec<Vector3<f32>>>,
+    uvs: Option<Vec<Point2<f32>>>,
+    colours: Option<Vec<Rgb>>,
+    attributes: BTreeMap<String, VertexAttribute>,
+    triangles: Vec<[u32; 3]>,
+    pub material: Option<usize>,
+}
+
+impl GltfPrimitive {
+    // Points and lines have no surface to render, so only triangle modes produce a primitive.
+    pub(crate) fn read(buffers: &Buffers, primitive: &Primitive) -> Result<Option<Self>, GltfError> {
+        if !matches!(primitive.mode, TRIANGLES | TRIANGLE_STRIP | TRIANGLE_FAN) {
+            return Ok(None);
+        }
+
+        let position_accessor = *primitive
+            .attributes
+            .get("POSITION")
+            .ok_or_else(|| invalid("primitive has no POSITION attribute"))?;
+        let (values, components) = attribute(buffers, position_accessor, &[3])?;
+        let positions: Vec<_> = values
+            .chunks_exact(components)
+            .map(|value| Point3::new(value[0], value[1], value[2]))
+            .collect();
+
+        let vertex_count = positions.len();
+        let optional = |name: &str, sizes: &[usize]| -> Result<Option<(Vec<f32>, usize)>, GltfError> {
+            let Some(&accessor) = primitive.attributes.get(name) else {
+                return Ok(None);
+            };
+
+            let (values, components) = attribute(buffers, accessor, sizes)?;
+            if values.len() != vertex_count * components {
+                return Err(invalid(&format!("{name} has a different count to POSITION")));
+            }
+
+            Ok(Some((values, components)))
+        };
+
+        let normals = optional("NORMAL", &[3])?.map(|(values, components)| {
+            values
+                .chunks_exact(components)
+                .map(|value| Vector3::new(value[0], value[1], value[2]))
+                .collect()
+        });
+        // glTF puts the texture origin at the top left, antler at the bottom left.
+        let uvs = optional("TEXCOORD_0", &[2])?.map(|(values, components)| {
+            values
+                .chunks_exact(components)
+                .map(|value| Point2::new(value[0], 1.0 - value[1]))
+                .collect()
+        });
+        let colours = optional("COLOR_0", &[3, 4])?.map(|(values, components)| {
+            values
+                .chunks_exact(components)
+                .map(|value| Rgb::new(value[0], value[1], value[2]))
+                .collect()
+        });
+
+        // Attributes antler does not shade with, such as further texture sets or tangents, are kept by name.
+        let attributes = primitive
+            .attributes
+            .keys()
+            .filter(|name| !BUILT_IN_ATTRIBUTES.contains(&name.as_str()))
+            .map(|name| {
+                let (values, components) = optional(name, &[1, 2, 3, 4])?.expect("attribute is listed");
+                Ok((name.clone(), VertexAttribute::new(components, values)))
+            })
+            .collect::<Result<_, GltfError>>()?;
+
+        let indices = match primitive.indices {
+            Some(accessor) => buffers.indices(accessor)?,
+            None => (0..vertex_count).collect(),
+        };
+        if let Some(&missing) = indices.iter().find(|&&index| index >= vertex_count) {
+            return Err(invalid(&format!("primitive refers to missing vertex {missing}")));
+        }
+
+        let indices: Vec<u32> = indices.into_iter().map(|index| index as u32).collect();
+        let triangles = match primitive.mode {
+            TRIANGLE_STRIP => (0..indices.len().saturating_sub(2))
+                .map(|k| {
+                    if k % 2 == 0 {
+                        [indices[k], indices[k + 1], indices[k + 2]]
+                    } else {
+                        [indices[k + 1], indices[k], indices[k + 2]]
+                    }
+                })
+                .collect(),
+            TRIANGLE_FAN => (1..indices.len().saturating_sub(1))
+                .map(|k| [indices[0], indices[k], indices[k + 1]])
+                .collect(),
+            _ => indices
+                .chunks_exact(3)
+                .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+                .collect(),
+        };
+
+        Ok(Some(Self {
+            positions,
+            normals,
+            uvs,
+            colours,
+            attributes,
+            triangles,
+            material: primitive.material,
+        }))
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn has_colours(&self) -> bool {
+        self.colours.is_some()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    // Normals are carried through the inverse transpose so non-uniform scales keep them perpendicular. Named
+    // attributes are passed on as stored, in the primitive's own frame.
+    #[must_use]
+    pub fn buffers(&self, transform: &Matrix4<f32>) -> MeshBuffers {
+        let normal_transform = transform
+            .fixed_view::<3, 3>(0, 0)
+            .try_inverse()
+            .map(|inverse| inverse.transpose());
+
+        let positions = self
+            .positions
+            .iter()
+            .map(|position| transform.transform_point(position))
+            .collect();
+        let normals = self
+            .normals
+            .as_ref()
+            .zip(normal_transform)
+            .map(|(normals, normal_transform)| normals.iter().map(|normal| normal_transform * normal).collect());
+
+        MeshBuffers::new(positions, self.triangles.clone())
+            .with_normals(normals)
+            .with_uvs(self.uvs.clone())
+            .with_colours(self.colours.clone())
+            .with_attributes(self.attributes.clone())
+    }
+}
+
+fn attribute(buffers: &Buffers, accessor: usize, sizes: &[usize]) -> Result<(Vec<f32>, usize), GltfError> {
+    let (values, components) = buffers.floats(accessor)?;
+
+    if !sizes.contains(&components) {
+        return Err(invalid(&format!("accessor {accessor} has {components} components")));
+    }
+
+    Ok((values, components))
+}
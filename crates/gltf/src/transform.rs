@@ -0,0 +1,25 @@
+use nalgebra::{Matrix3, Matrix4, Rotation3, Similarity3, Translation3, UnitQuaternion};
+
+const ORTHONORMAL_TOLERANCE: f32 = 1.0e-4;
+
+// Node transforms that only rotate, translate and scale uniformly can be shared by instances of one mesh.
+#[must_use]
+pub fn similarity(matrix: &Matrix4<f32>) -> Option<Similarity3<f32>> {
+    let linear: Matrix3<f32> = matrix.fixed_view::<3, 3>(0, 0).into_owned();
+
+    let scale = linear.determinant().cbrt();
+    if scale <= 0.0 || !scale.is_finite() {
+        return None;
+    }
+
+    let rotation = linear / scale;
+    if (rotation.transpose() * rotation - Matrix3::identity()).amax() > ORTHONORMAL_TOLERANCE {
+        return None;
+    }
+
+    Some(Similarity3::from_parts(
+        Translation3::new(matrix[(0, 3)], matrix[(1, 3)], matrix[(2, 3)]),
+        UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(rotation)),
+        scale,
+    ))
+}
@@ -0,0 +1,67 @@
+use std::{fs::File, io::BufReader, path::Path};
+
+use antler_grid::SurfaceGrid;
+use png::{BitDepth, ColorType, Decoder, Transformations};
+
+use crate::errors::ImageLoadError;
+
+// Loads a greyscale PNG as heights in [0, 1], flipping rows so the second coordinate increases up the image.
+pub fn load_height_map(path: impl AsRef<Path>) -> Result<SurfaceGrid<f32>, ImageLoadError> {
+    let file = File::open(path)?;
+    let mut decoder = Decoder::new(BufReader::new(file));
+    decoder.set_transformations(Transformations::EXPAND);
+    let mut reader = decoder.read_info()?;
+
+    let output_buffer_size = reader.output_buffer_size().ok_or(ImageLoadError::UnknownBufferSize)?;
+
+    let mut buffer = vec![0; output_buffer_size];
+    let info = reader.next_frame(&mut buffer)?;
+
+    let channels = match info.color_type {
+        ColorType::Grayscale => 1,
+        ColorType::GrayscaleAlpha => 2,
+        found => {
+            return Err(ImageLoadError::ColourTypeMismatch {
+                expected: ColorType::Grayscale,
+                found,
+            });
+        }
+    };
+
+    let (sample_bytes, max_value) = match info.bit_depth {
+        BitDepth::Eight => (1, f32::from(u8::MAX)),
+        BitDepth::Sixteen => (2, f32::from(u16::MAX)),
+        found => {
+            return Err(ImageLoadError::BitDepthMismatch {
+                expected: BitDepth::Sixteen,
+                found,
+            });
+        }
+    };
+
+    let width = usize::try_from(info.width).expect("image width exceeds usize::MAX");
+    let height = usize::try_from(info.height).expect("image height exceeds usize::MAX");
+    let pixel_bytes = channels * sample_bytes;
+
+    let mut heights = Vec::with_capacity(width * height);
+
+    for row in buffer[..info.buffer_size()].chunks_exact(info.line_size).rev() {
+        if row.len() < width * pixel_bytes {
+            return Err(ImageLoadError::InvalidByteLength {
+                len: row.len(),
+                channels,
+            });
+        }
+
+        heights.extend(row.chunks_exact(pixel_bytes).take(width).map(|pixel| {
+            let value = if sample_bytes == 2 {
+                f32::from(u16::from_be_bytes([pixel[0], pixel[1]]))
+            } else {
+                f32::from(pixel[0])
+            };
+            value / max_value
+        }));
+    }
+
+    Ok(SurfaceGrid::from_vec([width, height], heights))
+}
@@ -1,6 +1,6 @@
 use std::{
     fs::File,
-    io::{BufReader, BufWriter, Error as IoError, Result as IoResult},
+    io::{BufRead, BufReader, BufWriter, Error as IoError, Result as IoResult, Seek, Write},
     ops::{Index, IndexMut},
     path::Path,
 };
@@ -12,6 +12,7 @@ use png::{Decoder, Encoder};
 
 use crate::{errors::ImageLoadError, tile::Tile};
 
+#[derive(Clone)]
 pub struct Image<P: Pixel> {
     pixels: SurfaceGrid<P>,
 }
@@ -36,9 +37,18 @@ impl<P: Pixel> Image<P> {
         }
     }
 
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> [usize; 2] {
+        self.pixels.size()
+    }
+
     pub fn load(path: impl AsRef<Path>) -> Result<Self, ImageLoadError> {
-        let file = File::open(path)?;
-        let decoder = Decoder::new(BufReader::new(file));
+        Self::decode(BufReader::new(File::open(path)?))
+    }
+
+    pub fn decode<R: BufRead + Seek>(source: R) -> Result<Self, ImageLoadError> {
+        let decoder = Decoder::new(source);
         let mut reader = decoder.read_info()?;
 
         let output_buffer_size = reader.output_buffer_size().ok_or(ImageLoadError::UnknownBufferSize)?;
@@ -78,24 +88,80 @@ impl<P: Pixel> Image<P> {
     }
 
     pub fn save(&self, path: impl AsRef<Path>) -> IoResult<()> {
-        let width = u32::try_from(self.pixels.size()[0]).expect("image width exceeds u32::MAX");
-        let height = u32::try_from(self.pixels.size()[1]).expect("image height exceeds u32::MAX");
+        let file = File::create(path)?;
+        self.write_png(BufWriter::new(file))
+    }
 
-        let mut bytes = Vec::with_capacity(self.pixels.len() * P::CHANNELS);
+    pub fn write_png<W: Write>(&self, writer: W) -> IoResult<()> {
+        let mut writer = self.encoder(writer).write_header().map_err(IoError::other)?;
+        writer.write_image_data(&self.png_bytes()).map_err(IoError::other)
+    }
 
-        for pixel in self.pixels.as_slice() {
-            bytes.extend_from_slice(pixel.to_bytes().as_ref());
+    pub fn save_animated(frames: &[Self], fps: u16, plays: u32, path: impl AsRef<Path>) -> IoResult<()> {
+        let file = File::create(path)?;
+        Self::write_animated_png(frames, fps, plays, BufWriter::new(file))
+    }
+
+    // Writes an APNG, which viewers without animation support show as its first frame.
+    pub fn write_animated_png<W: Write>(frames: &[Self], fps: u16, plays: u32, writer: W) -> IoResult<()> {
+        assert!(!frames.is_empty(), "An animation needs at least one frame.");
+        assert!(fps > 0, "Animation frame rate must be positive.");
+        assert!(
+            frames.iter().all(|frame| frame.size() == frames[0].size()),
+            "Animation frames must all be the same size."
+        );
+
+        let frame_count = u32::try_from(frames.len()).expect("frame count exceeds u32::MAX");
+
+        let mut encoder = frames[0].encoder(writer);
+        encoder.set_animated(frame_count, plays).map_err(IoError::other)?;
+        encoder.set_frame_delay(1, fps).map_err(IoError::other)?;
+
+        let mut writer = encoder.write_header().map_err(IoError::other)?;
+        for frame in frames {
+            writer.write_image_data(&frame.png_bytes()).map_err(IoError::other)?;
         }
+        writer.finish().map_err(IoError::other)
+    }
 
-        let file = File::create(path)?;
-        let writer = BufWriter::new(file);
+    #[must_use]
+    pub fn contact_sheet(frames: &[Self], columns: usize, background: P) -> Self
+    where
+        P: Clone,
+    {
+        assert!(!frames.is_empty(), "A contact sheet needs at least one frame.");
+        assert!(columns > 0, "A contact sheet needs at least one column.");
+
+        let [width, height] = frames[0].size();
+        let columns = columns.min(frames.len());
+        let rows = frames.len().div_ceil(columns);
+
+        let mut sheet = Self::filled([width * columns, height * rows], background);
+        for (index, frame) in frames.iter().enumerate() {
+            sheet.paste(frame, [index % columns * width, index / columns * height]);
+        }
+
+        sheet
+    }
+
+    fn encoder<W: Write>(&self, writer: W) -> Encoder<'static, W> {
+        let width = u32::try_from(self.pixels.size()[0]).expect("image width exceeds u32::MAX");
+        let height = u32::try_from(self.pixels.size()[1]).expect("image height exceeds u32::MAX");
 
         let mut encoder = Encoder::new(writer, width, height);
         encoder.set_color(P::PNG_COLOUR_TYPE);
         encoder.set_depth(P::PNG_BIT_DEPTH);
+        encoder
+    }
 
-        let mut writer = encoder.write_header().map_err(IoError::other)?;
-        writer.write_image_data(&bytes).map_err(IoError::other)
+    fn png_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.pixels.len() * P::CHANNELS);
+
+        for pixel in self.pixels.as_slice() {
+            bytes.extend_from_slice(pixel.to_bytes().as_ref());
+        }
+
+        bytes
     }
 
     #[must_use]
@@ -126,6 +192,22 @@ impl<P: Pixel> Image<P> {
         }
     }
 
+    #[inline]
+    pub fn paste(&mut self, other: &Self, position: [usize; 2]) {
+        let [width, height] = other.size();
+
+        assert!(
+            position[0] + width <= self.size()[0] && position[1] + height <= self.size()[1],
+            "Pasted image must fit within the target image"
+        );
+
+        for y in 0..height {
+            for x in 0..width {
+                self[(position[0] + x, position[1] + y)] = other[(x, y)];
+            }
+        }
+    }
+
     #[inline]
     pub fn tone_map(&mut self) {
         for pixel in self.pixels.as_mut_slice() {
@@ -1,6 +1,10 @@
 pub mod errors;
+mod height_map;
 mod image;
+mod png_row_writer;
 mod tile;
 
+pub use height_map::load_height_map;
 pub use image::{Image, RgbImage, RgbaImage};
+pub use png_row_writer::PngRowWriter;
 pub use tile::Tile;
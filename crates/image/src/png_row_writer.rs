@@ -0,0 +1,99 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Error as IoError, Result as IoResult, Write},
+    marker::PhantomData,
+    path::Path,
+};
+
+use antler_colour::Pixel;
+use png::{Encoder, StreamWriter};
+
+pub struct PngRowWriter<P: Pixel, W: Write + 'static> {
+    writer: StreamWriter<'static, W>,
+    size: [usize; 2],
+    rows_written: usize,
+    bytes: Vec<u8>,
+    _pixel: PhantomData<P>,
+}
+
+impl<P: Pixel> PngRowWriter<P, BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>, size: [usize; 2]) -> IoResult<Self> {
+        let file = File::create(path)?;
+        Self::new(BufWriter::new(file), size)
+    }
+}
+
+impl<P: Pixel, W: Write + 'static> PngRowWriter<P, W> {
+    pub fn new(writer: W, size: [usize; 2]) -> IoResult<Self> {
+        let width = u32::try_from(size[0]).expect("image width exceeds u32::MAX");
+        let height = u32::try_from(size[1]).expect("image height exceeds u32::MAX");
+
+        let mut encoder = Encoder::new(writer, width, height);
+        encoder.set_color(P::PNG_COLOUR_TYPE);
+        encoder.set_depth(P::PNG_BIT_DEPTH);
+
+        let writer = encoder
+            .write_header()
+            .and_then(png::Writer::into_stream_writer)
+            .map_err(IoError::other)?;
+
+        Ok(Self {
+            writer,
+            size,
+            rows_written: 0,
+            bytes: Vec::with_capacity(size[0] * P::CHANNELS),
+            _pixel: PhantomData,
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> [usize; 2] {
+        self.size
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn rows_written(&self) -> usize {
+        self.rows_written
+    }
+
+    pub fn write_row(&mut self, row: &[P]) -> IoResult<()> {
+        assert_eq!(row.len(), self.size[0], "Row length must match the image width.");
+        assert!(
+            self.rows_written < self.size[1],
+            "All image rows have already been written."
+        );
+
+        self.bytes.clear();
+        for pixel in row {
+            self.bytes.extend_from_slice(pixel.to_bytes().as_ref());
+        }
+
+        self.writer.write_all(&self.bytes)?;
+        self.rows_written += 1;
+        Ok(())
+    }
+
+    pub fn write_rows(&mut self, rows: &[P]) -> IoResult<()> {
+        assert_eq!(
+            rows.len() % self.size[0],
+            0,
+            "Rows must be a whole number of image widths."
+        );
+
+        for row in rows.chunks_exact(self.size[0]) {
+            self.write_row(row)?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> IoResult<()> {
+        assert_eq!(
+            self.rows_written, self.size[1],
+            "All image rows must be written before finishing."
+        );
+
+        self.writer.finish().map_err(IoError::other)
+    }
+}
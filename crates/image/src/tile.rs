@@ -16,15 +16,21 @@ impl Tile {
     #[must_use]
     #[inline]
     pub fn create_tiles(resolution: [usize; 2], tile_size: [usize; 2]) -> Vec<Self> {
-        let [tiles_x, tiles_y] = Self::count_tiles(resolution, tile_size);
+        Self::create_tiles_within([0, 0], resolution, tile_size)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn create_tiles_within(min: [usize; 2], max: [usize; 2], tile_size: [usize; 2]) -> Vec<Self> {
+        let [tiles_x, tiles_y] = Self::count_tiles([max[0] - min[0], max[1] - min[1]], tile_size);
 
         (0..tiles_y)
             .flat_map(|ty| {
                 (0..tiles_x).map(move |tx| {
-                    let min_x = tx * tile_size[0];
-                    let min_y = ty * tile_size[1];
-                    let max_x = (min_x + tile_size[0]).min(resolution[0]);
-                    let max_y = (min_y + tile_size[1]).min(resolution[1]);
+                    let min_x = min[0] + tx * tile_size[0];
+                    let min_y = min[1] + ty * tile_size[1];
+                    let max_x = (min_x + tile_size[0]).min(max[0]);
+                    let max_y = (min_y + tile_size[1]).min(max[1]);
 
                     Self::new([min_x, min_y], [max_x, max_y])
                 })
@@ -52,4 +58,13 @@ impl Tile {
         let [width, height] = self.size();
         width * height
     }
+
+    #[must_use]
+    #[inline]
+    pub const fn translated(&self, origin: [usize; 2]) -> Self {
+        Self::new(
+            [self.min[0] - origin[0], self.min[1] - origin[1]],
+            [self.max[0] - origin[0], self.max[1] - origin[1]],
+        )
+    }
 }
@@ -34,6 +34,7 @@ impl Bsdf for Ggx {
                 Ray {
                     origin: offset_origin(contact.position, contact.normal, reflected),
                     direction: reflected,
+                    time: ray.time,
                 },
                 self.reflectance,
             );
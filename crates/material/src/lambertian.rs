@@ -20,19 +20,14 @@ impl Lambertian {
 }
 
 impl Bsdf for Lambertian {
-    fn scatter<R: Rng, F: FnMut(Ray, f32)>(
-        &self,
-        rng: &mut R,
-        _ray: &Ray,
-        contact: &Contact,
-        mut emit_child: F,
-    ) -> f32 {
+    fn scatter<R: Rng, F: FnMut(Ray, f32)>(&self, rng: &mut R, ray: &Ray, contact: &Contact, mut emit_child: F) -> f32 {
         let direction = cosine_weighted_hemisphere(rng, contact.normal);
 
         emit_child(
             Ray {
                 origin: offset_origin(contact.position, contact.normal, direction),
                 direction,
+                time: ray.time,
             },
             self.albedo,
         );
@@ -26,6 +26,7 @@ impl Bsdf for Mirror {
             Ray {
                 origin: offset_origin(contact.position, contact.normal, direction),
                 direction,
+                time: ray.time,
             },
             1.0,
         );
@@ -30,6 +30,7 @@ impl Bsdf for Reflective {
             Ray {
                 origin: offset_origin(contact.position, contact.normal, direction),
                 direction,
+                time: ray.time,
             },
             self.reflectance,
         );
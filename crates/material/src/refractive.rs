@@ -61,6 +61,7 @@ impl Bsdf for Refractive {
             Ray {
                 origin: offset_origin(contact.position, outward_normal, reflected),
                 direction: reflected,
+                time: ray.time,
             },
             reflectance,
         );
@@ -72,6 +73,7 @@ impl Bsdf for Refractive {
                 Ray {
                     origin: offset_origin(contact.position, outward_normal, refracted),
                     direction: refracted,
+                    time: ray.time,
                 },
                 1.0 - reflectance,
             );
@@ -35,6 +35,7 @@ impl Bsdf for Transparent {
             Ray {
                 origin: offset_origin(contact.position, -contact.normal, ray.direction),
                 direction: ray.direction,
+                time: ray.time,
             },
             self.transparency,
         );
@@ -10,7 +10,7 @@ pub struct Wireframe {
 }
 
 impl Wireframe {
-    #[must_use] 
+    #[must_use]
     pub const fn new(transparency: f32, line_width: f32) -> Self {
         Self {
             transparency: transparency.clamp(0.0, 1.0),
@@ -45,6 +45,7 @@ impl Bsdf for Wireframe {
                 Ray {
                     origin: offset_origin(contact.position, -contact.normal, ray.direction),
                     direction: ray.direction,
+                    time: ray.time,
                 },
                 self.transparency,
             );
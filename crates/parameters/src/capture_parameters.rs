@@ -1,9 +1,10 @@
 use std::collections::BTreeMap;
 
 use antler_camera::Camera;
-use antler_settings::ImageSettings;
+use antler_settings::{ImageSettings, Sequence};
 
 pub struct CaptureParameters {
-    pub camera: Camera,
+    pub cameras: Vec<Camera>,
     pub images: BTreeMap<String, ImageSettings>,
+    pub sequence: Option<Sequence>,
 }
@@ -0,0 +1,66 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Error as IoError,
+};
+
+#[derive(Debug)]
+pub enum ShardError {
+    Io(IoError),
+    InvalidMagic,
+    UnsupportedVersion(u32),
+    InvalidName,
+    NameTooLong(usize),
+    InvalidStatus(u8),
+    InvalidTile { min: [usize; 2], max: [usize; 2] },
+    NoShards,
+    NameMismatch { expected: String, found: String },
+    FingerprintMismatch { name: String },
+    LayoutMismatch { name: String },
+    Incomplete { name: String },
+    MissingTiles { name: String, missing: usize, total: usize },
+}
+
+impl From<IoError> for ShardError {
+    fn from(err: IoError) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl Display for ShardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Io(err) => write!(f, "IO error: {err}"),
+            Self::InvalidMagic => write!(f, "File is not an antler shard"),
+            Self::UnsupportedVersion(version) => write!(f, "Unsupported shard version {version}"),
+            Self::InvalidName => write!(f, "Shard image name is not valid UTF-8"),
+            Self::NameTooLong(length) => write!(f, "Shard image name length {length} is too long"),
+            Self::InvalidStatus(status) => write!(f, "Shard has an invalid render status {status}"),
+            Self::InvalidTile { min, max } => write!(f, "Shard contains an invalid tile {min:?}..{max:?}"),
+            Self::NoShards => write!(f, "No shards to merge"),
+            Self::NameMismatch { expected, found } => {
+                write!(f, "Cannot merge shards of different images: '{expected}' and '{found}'")
+            }
+            Self::FingerprintMismatch { name } => write!(
+                f,
+                "Cannot merge shards of '{name}': they were rendered from different manifests or settings"
+            ),
+            Self::LayoutMismatch { name } => {
+                write!(f, "Cannot merge shards of '{name}': they have different image layouts")
+            }
+            Self::Incomplete { name } => write!(f, "A shard of '{name}' was cancelled or timed out"),
+            Self::MissingTiles { name, missing, total } => {
+                write!(f, "Shards of '{name}' are missing {missing} of {total} tiles")
+            }
+        }
+    }
+}
+
+impl Error for ShardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
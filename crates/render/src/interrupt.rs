@@ -0,0 +1,57 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+use crate::cancel_token::CancelToken;
+
+pub struct Interrupt {
+    cancel: Option<CancelToken>,
+    deadline: Option<Instant>,
+    triggered: AtomicBool,
+}
+
+impl Interrupt {
+    #[must_use]
+    pub const fn new(cancel: Option<CancelToken>, deadline: Option<Instant>) -> Self {
+        Self {
+            cancel,
+            deadline,
+            triggered: AtomicBool::new(false),
+        }
+    }
+
+    #[must_use]
+    pub const fn never() -> Self {
+        Self::new(None, None)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn check(&self) -> bool {
+        if self.triggered.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        let cancelled = self.cancel.as_ref().is_some_and(CancelToken::is_cancelled);
+        let expired = self.deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+        if cancelled || expired {
+            self.triggered.store(true, Ordering::Relaxed);
+        }
+
+        cancelled || expired
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_triggered(&self) -> bool {
+        self.triggered.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(CancelToken::is_cancelled)
+    }
+}
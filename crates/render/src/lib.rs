@@ -1,6 +1,27 @@
+mod cancel_token;
+pub mod errors;
+mod interrupt;
 mod probe;
+mod progress;
 mod render;
+mod render_options;
+mod render_output;
+mod render_stream;
+mod rendered_tile;
+mod shard;
+mod terminal_progress;
+mod tile_selection;
 mod utils;
 
+pub use cancel_token::CancelToken;
+pub use interrupt::Interrupt;
 pub use probe::Probe;
-pub use render::{render_image, render_probe, render_tile};
+pub use progress::{Progress, ProgressReport, Silent};
+pub use render::{compose_images, render_image, render_probe, render_tile, render_tiles};
+pub use render_options::RenderOptions;
+pub use render_output::{RenderOutput, RenderStatus};
+pub use render_stream::render_image_streaming;
+pub use rendered_tile::RenderedTile;
+pub use shard::Shard;
+pub use terminal_progress::TerminalProgress;
+pub use tile_selection::TileSelection;
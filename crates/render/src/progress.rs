@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressReport {
+    pub tiles_done: usize,
+    pub tiles_total: usize,
+    pub samples_done: u64,
+    pub samples_total: u64,
+    pub elapsed: Duration,
+}
+
+impl ProgressReport {
+    #[must_use]
+    #[inline]
+    pub fn fraction(&self) -> f32 {
+        if self.samples_total == 0 {
+            return 1.0;
+        }
+
+        (self.samples_done as f64 / self.samples_total as f64) as f32
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn eta(&self) -> Option<Duration> {
+        if self.samples_done == 0 {
+            return None;
+        }
+
+        let remaining = self.samples_total.saturating_sub(self.samples_done);
+        Some(self.elapsed.mul_f64(remaining as f64 / self.samples_done as f64))
+    }
+}
+
+pub trait Progress: Send + Sync {
+    #[inline]
+    fn start(&self, _report: &ProgressReport) {}
+
+    fn update(&self, report: &ProgressReport);
+
+    #[inline]
+    fn finish(&self, _report: &ProgressReport) {}
+}
+
+impl<F> Progress for F
+where
+    F: Fn(&ProgressReport) + Send + Sync,
+{
+    #[inline]
+    fn update(&self, report: &ProgressReport) {
+        self(report);
+    }
+}
+
+pub struct Silent;
+
+impl Progress for Silent {
+    #[inline]
+    fn update(&self, _report: &ProgressReport) {}
+}
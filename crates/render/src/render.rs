@@ -1,4 +1,8 @@
-use std::time::{Duration, Instant};
+use std::{
+    convert::Infallible,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
 
 use antler_camera::{Camera, Observer};
 use antler_colour::{Rgb, Rgba};
@@ -10,7 +14,14 @@ use nalgebra::Point2;
 use rand::{Rng, SeedableRng, rngs::SmallRng};
 use rayon::prelude::*;
 
-use crate::{probe::Probe, utils::progress_bar};
+use crate::{
+    interrupt::Interrupt,
+    probe::Probe,
+    progress::ProgressReport,
+    render_options::RenderOptions,
+    render_output::{RenderOutput, RenderStatus},
+    rendered_tile::RenderedTile,
+};
 
 const SKYBOX_IS_VISIBLE_TO_CAMERA: bool = true;
 
@@ -55,7 +66,7 @@ pub fn render_probe<R: Rng + SeedableRng>(
         }
     });
 
-    let ao = scene.occlusion(rng, resources, &mut contact);
+    let ao = scene.occlusion(rng, resources, &probe.ray, &mut contact);
 
     let emitted = object.emissive.as_ref().map_or(Rgb::BLACK, |e| e.colour * e.intensity);
     let ambient = scene.ambient_shade(shader, &probe.ray, &contact) * ao * local_fraction;
@@ -67,8 +78,10 @@ pub fn render_probe<R: Rng + SeedableRng>(
     Some(local * probe.weight + bounced * lighting_settings.indirect)
 }
 
-pub fn render_tile<R: Rng + SeedableRng>(
-    rng: &mut R,
+// Each pixel seeds its own generator from its full-frame coordinates, so it renders the same whichever tile it falls in
+// and a cropped render matches the pixels of the whole frame.
+#[allow(clippy::too_many_arguments)]
+pub fn render_tile(
     image_settings: &ImageSettings,
     lighting_settings: &LightingSettings,
     probe_settings: &ProbeSettings,
@@ -76,51 +89,71 @@ pub fn render_tile<R: Rng + SeedableRng>(
     resources: &Resources,
     scene: &Scene,
     tile: Tile,
-) -> (Vec<Rgba>, Vec<Duration>) {
+    interrupt: &Interrupt,
+) -> (Vec<Rgba>, Vec<Duration>, u64) {
     let image_width = image_settings.resolution[0] as f32;
     let image_height = image_settings.resolution[1] as f32;
     let ss = image_settings.super_samples.max(1);
     let ss_delta = 1.0 / ss as f32;
-    let inv_samples = 1.0 / (ss * ss) as f32;
+    let exposure = camera.exposure();
 
     let [tile_width, tile_height] = tile.size();
     let mut pixels = vec![image_settings.background; tile.num_pixels()];
-    let mut times = Vec::with_capacity(tile.num_pixels());
+    let mut times = vec![Duration::ZERO; tile.num_pixels()];
+    let mut samples_rendered = 0;
 
-    for local_y in 0..tile_height {
+    'tile: for local_y in 0..tile_height {
         let y = tile.min[1] + local_y;
 
         for local_x in 0..tile_width {
             let x = tile.min[0] + local_x;
+            let rng = &mut SmallRng::seed_from_u64(pixel_seed([x, y]));
 
             let mut colour = Rgba::TRANSPARENT;
+            let mut samples = 0;
 
             let mut total_duration = Duration::from_secs(0);
-            for sy in 0..ss {
+            'pixel: for sy in 0..ss {
                 for sx in 0..ss {
+                    if interrupt.check() {
+                        break 'pixel;
+                    }
+
                     let uv = Point2::new(
                         (sx as f32 + 0.5).mul_add(ss_delta, x as f32) / image_width,
                         (sy as f32 + 0.5).mul_add(ss_delta, y as f32) / image_height,
                     );
 
-                    let ray = camera.emit(image_settings.resolution, uv);
-                    let probe = Probe::new(ray);
-
                     let start_time = Instant::now();
-                    let sample = render_probe(rng, lighting_settings, probe_settings, resources, scene, probe)
-                        .map_or(image_settings.background, |rgb| rgb.to_rgba());
+                    let sample = camera
+                        .emit(rng, image_settings.resolution, uv)
+                        .and_then(|camera_ray| {
+                            let probe = Probe::new(camera_ray.ray);
+                            render_probe(rng, lighting_settings, probe_settings, resources, scene, probe)
+                                .map(|rgb| rgb * camera_ray.weight)
+                        })
+                        .map_or(image_settings.background, |rgb| (rgb * exposure).to_rgba());
                     total_duration += start_time.elapsed();
                     colour += sample;
+                    samples += 1;
                 }
             }
 
             let index = local_y * tile_width + local_x;
-            pixels[index] = colour * inv_samples;
-            times.push(total_duration);
+            samples_rendered += samples as u64;
+
+            if samples > 0 {
+                pixels[index] = colour / samples as f32;
+                times[index] = total_duration;
+            }
+
+            if samples < ss * ss {
+                break 'tile;
+            }
         }
     }
 
-    (pixels, times)
+    (pixels, times, samples_rendered)
 }
 
 #[must_use]
@@ -131,22 +164,117 @@ pub fn render_image(
     camera: &Camera,
     resources: &Resources,
     scene: &Scene,
-) -> (RgbaImage, RgbaImage) {
-    let tiles = Tile::create_tiles(image_settings.resolution, image_settings.tile_size);
+    options: &RenderOptions,
+) -> RenderOutput {
+    let (region_min, region_max) = image_settings.region();
+    let tiles = Tile::create_tiles_within(region_min, region_max, image_settings.tile_size);
+
+    let (rendered_tiles, status) = render_tiles(
+        image_settings,
+        lighting_settings,
+        probe_settings,
+        camera,
+        resources,
+        scene,
+        tiles,
+        options,
+    );
+
+    let (visual, temporal) = compose_images(
+        image_settings.background,
+        image_settings.output_origin(),
+        image_settings.output_resolution(),
+        &rendered_tiles,
+    );
+
+    RenderOutput {
+        visual,
+        temporal,
+        status,
+    }
+}
 
-    let pb = progress_bar(tiles.len() as u64);
-    let rendered_tiles = tiles
-        .into_par_iter()
-        .map(|tile| {
-            pb.inc(1);
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn render_tiles(
+    image_settings: &ImageSettings,
+    lighting_settings: &LightingSettings,
+    probe_settings: &ProbeSettings,
+    camera: &Camera,
+    resources: &Resources,
+    scene: &Scene,
+    tiles: Vec<Tile>,
+    options: &RenderOptions,
+) -> (Vec<RenderedTile>, RenderStatus) {
+    let mut rendered_tiles = Vec::new();
+
+    let Ok(status) = render_tile_batches(
+        image_settings,
+        lighting_settings,
+        probe_settings,
+        camera,
+        resources,
+        scene,
+        vec![tiles],
+        options,
+        |batch| {
+            rendered_tiles.extend(batch);
+            Ok::<_, Infallible>(())
+        },
+    );
+
+    (rendered_tiles, status)
+}
 
-            let seed = tile_seed(tile.min);
-            let mut rng = SmallRng::seed_from_u64(seed);
+#[allow(clippy::too_many_arguments)]
+pub fn render_tile_batches<E>(
+    image_settings: &ImageSettings,
+    lighting_settings: &LightingSettings,
+    probe_settings: &ProbeSettings,
+    camera: &Camera,
+    resources: &Resources,
+    scene: &Scene,
+    batches: Vec<Vec<Tile>>,
+    options: &RenderOptions,
+    mut sink: impl FnMut(Vec<RenderedTile>) -> Result<(), E>,
+) -> Result<RenderStatus, E> {
+    let start_time = Instant::now();
+    let interrupt = Interrupt::new(
+        options.cancel.clone(),
+        options.time_budget.map(|budget| start_time + budget),
+    );
+
+    let ss = image_settings.super_samples.max(1) as u64;
+    let tiles_total = batches.iter().map(Vec::len).sum();
+    let samples_total = batches
+        .iter()
+        .flatten()
+        .map(|tile| tile.num_pixels() as u64)
+        .sum::<u64>()
+        * ss
+        * ss;
+
+    let tiles_done = AtomicUsize::new(0);
+    let samples_done = AtomicU64::new(0);
+    let report = |tiles_done: usize, samples_done: u64| ProgressReport {
+        tiles_done,
+        tiles_total,
+        samples_done,
+        samples_total,
+        elapsed: start_time.elapsed(),
+    };
+
+    options.progress.start(&report(0, 0));
+
+    for batch in batches {
+        let rendered_tiles = batch
+            .into_par_iter()
+            .filter_map(|tile| {
+                if interrupt.check() {
+                    return None;
+                }
 
-            (
-                tile,
-                render_tile(
-                    &mut rng,
+                let (pixels, times, tile_samples) = render_tile(
                     image_settings,
                     lighting_settings,
                     probe_settings,
@@ -154,32 +282,63 @@ pub fn render_image(
                     resources,
                     scene,
                     tile,
-                ),
-            )
-        })
-        .collect::<Vec<_>>();
-    pb.finish();
+                    &interrupt,
+                );
+
+                let tiles = tiles_done.fetch_add(1, Ordering::Relaxed) + 1;
+                let samples = samples_done.fetch_add(tile_samples, Ordering::Relaxed) + tile_samples;
+                options.progress.update(&report(tiles, samples));
+
+                Some(RenderedTile { tile, pixels, times })
+            })
+            .collect::<Vec<_>>();
+
+        sink(rendered_tiles)?;
+    }
+
+    options.progress.finish(&report(
+        tiles_done.load(Ordering::Relaxed),
+        samples_done.load(Ordering::Relaxed),
+    ));
+
+    let status = if !interrupt.is_triggered() {
+        RenderStatus::Complete
+    } else if interrupt.is_cancelled() {
+        RenderStatus::Cancelled
+    } else {
+        RenderStatus::TimedOut
+    };
+
+    Ok(status)
+}
 
+#[must_use]
+pub fn compose_images(
+    background: Rgba,
+    origin: [usize; 2],
+    resolution: [usize; 2],
+    rendered_tiles: &[RenderedTile],
+) -> (RgbaImage, RgbaImage) {
     let (min_time, max_time) = rendered_tiles
         .iter()
-        .flat_map(|(_, (_, times))| times.iter())
+        .flat_map(|rendered| rendered.times.iter())
+        .filter(|time| !time.is_zero())
         .fold((Duration::MAX, Duration::ZERO), |(min, max), &t| {
             (min.min(t), max.max(t))
         });
 
-    let mut visual_image = RgbaImage::filled(image_settings.resolution, image_settings.background);
-    let mut temporal_image = RgbaImage::filled(image_settings.resolution, Rgba::TRANSPARENT);
+    let mut visual_image = RgbaImage::filled(resolution, background);
+    let mut temporal_image = RgbaImage::filled(resolution, Rgba::TRANSPARENT);
 
-    for (tile, (pixels, times)) in rendered_tiles {
-        visual_image.apply_tile(tile, &pixels);
+    for rendered in rendered_tiles {
+        let tile = rendered.tile.translated(origin);
+        visual_image.apply_tile(tile, &rendered.pixels);
         temporal_image.apply_tile(
             tile,
-            &times
+            &rendered
+                .times
                 .iter()
-                .map(|time| {
-                    let t = normalise_log_duration(*time, min_time, max_time);
-                    Rgba::new(t, t, t, 1.0)
-                })
+                .map(|&time| temporal_pixel(time, min_time, max_time))
                 .collect::<Vec<_>>(),
         );
     }
@@ -187,6 +346,17 @@ pub fn render_image(
     (visual_image, temporal_image)
 }
 
+#[must_use]
+#[inline]
+pub fn temporal_pixel(time: Duration, min: Duration, max: Duration) -> Rgba {
+    if time.is_zero() {
+        return Rgba::TRANSPARENT;
+    }
+
+    let t = normalise_log_duration(time, min, max);
+    Rgba::new(t, t, t, 1.0)
+}
+
 #[must_use]
 #[inline]
 fn normalise_log_duration(time: Duration, min: Duration, max: Duration) -> f32 {
@@ -199,7 +369,7 @@ fn normalise_log_duration(time: Duration, min: Duration, max: Duration) -> f32 {
 
 #[must_use]
 #[inline]
-const fn tile_seed(coord: [usize; 2]) -> u64 {
+const fn pixel_seed(coord: [usize; 2]) -> u64 {
     let [x, y] = coord;
 
     let mut z = (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ (y as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9);
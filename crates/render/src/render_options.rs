@@ -0,0 +1,43 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    cancel_token::CancelToken,
+    progress::{Progress, Silent},
+};
+
+#[derive(Clone)]
+pub struct RenderOptions {
+    pub progress: Arc<dyn Progress>,
+    pub cancel: Option<CancelToken>,
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            progress: Arc::new(Silent),
+            cancel: None,
+            time_budget: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    #[must_use]
+    pub fn with_progress<P: Progress + 'static>(mut self, progress: P) -> Self {
+        self.progress = Arc::new(progress);
+        self
+    }
+
+    #[must_use]
+    pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_time_budget(mut self, time_budget: Duration) -> Self {
+        self.time_budget = Some(time_budget);
+        self
+    }
+}
@@ -0,0 +1,22 @@
+use antler_image::RgbaImage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderStatus {
+    Complete,
+    Cancelled,
+    TimedOut,
+}
+
+pub struct RenderOutput {
+    pub visual: RgbaImage,
+    pub temporal: RgbaImage,
+    pub status: RenderStatus,
+}
+
+impl RenderOutput {
+    #[must_use]
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.status == RenderStatus::Complete
+    }
+}
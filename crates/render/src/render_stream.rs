@@ -0,0 +1,143 @@
+use std::{
+    fs::{File, remove_file},
+    io::{BufReader, BufWriter, Error as IoError, Read, Result as IoResult, Write},
+    path::Path,
+    time::Duration,
+};
+
+use antler_camera::Camera;
+use antler_colour::Rgba;
+use antler_image::{PngRowWriter, Tile};
+use antler_scene::{Resources, Scene};
+use antler_settings::{ImageSettings, LightingSettings, ProbeSettings};
+
+use crate::{
+    render::{render_tile_batches, temporal_pixel},
+    render_options::RenderOptions,
+    render_output::RenderStatus,
+};
+
+const NANOS_BYTES: usize = size_of::<u64>();
+
+#[allow(clippy::too_many_arguments)]
+pub fn render_image_streaming(
+    image_settings: &ImageSettings,
+    lighting_settings: &LightingSettings,
+    probe_settings: &ProbeSettings,
+    camera: &Camera,
+    resources: &Resources,
+    scene: &Scene,
+    options: &RenderOptions,
+    visual_path: &Path,
+    temporal_path: &Path,
+) -> IoResult<RenderStatus> {
+    let (region_min, region_max) = image_settings.region();
+    let origin = image_settings.output_origin();
+    let resolution = image_settings.output_resolution();
+    let [width, height] = resolution;
+    let background = image_settings.background;
+
+    // Tiles are created row-major, so each run of equal minimum y is one band of image rows.
+    let bands = Tile::create_tiles_within(region_min, region_max, image_settings.tile_size)
+        .chunk_by(|a, b| a.min[1] == b.min[1])
+        .map(<[Tile]>::to_vec)
+        .collect::<Vec<_>>();
+
+    // Render times can only be normalised once every tile is done, so they are spooled to disk until then.
+    let times_path = temporal_path.with_extension("times");
+    let mut times_writer = BufWriter::new(File::create(&times_path)?);
+    let mut visual = PngRowWriter::<Rgba, _>::create(visual_path, resolution)?;
+    let mut time_range = (Duration::MAX, Duration::ZERO);
+
+    let background_row = vec![background; width];
+    let empty_times_row = vec![0; width * NANOS_BYTES];
+
+    for _ in 0..region_min[1] - origin[1] {
+        visual.write_row(&background_row)?;
+        times_writer.write_all(&empty_times_row)?;
+    }
+
+    let status = render_tile_batches(
+        image_settings,
+        lighting_settings,
+        probe_settings,
+        camera,
+        resources,
+        scene,
+        bands,
+        options,
+        |band| {
+            let band_min = visual.rows_written();
+            let band_height = image_settings.tile_size[1].min(region_max[1] - origin[1] - band_min);
+
+            let mut pixels = vec![background; width * band_height];
+            let mut times = vec![Duration::ZERO; width * band_height];
+
+            for rendered in band {
+                let tile = rendered.tile.translated(origin);
+                let [tile_width, tile_height] = tile.size();
+
+                for local_y in 0..tile_height {
+                    let start = (tile.min[1] - band_min + local_y) * width + tile.min[0];
+                    let source = local_y * tile_width..(local_y + 1) * tile_width;
+
+                    pixels[start..start + tile_width].copy_from_slice(&rendered.pixels[source.clone()]);
+                    times[start..start + tile_width].copy_from_slice(&rendered.times[source]);
+                }
+            }
+
+            visual.write_rows(&pixels)?;
+
+            for time in times {
+                if !time.is_zero() {
+                    time_range = (time_range.0.min(time), time_range.1.max(time));
+                }
+                times_writer.write_all(&u64::try_from(time.as_nanos()).unwrap_or(u64::MAX).to_le_bytes())?;
+            }
+
+            Ok::<_, IoError>(())
+        },
+    )?;
+
+    for _ in visual.rows_written()..height {
+        visual.write_row(&background_row)?;
+        times_writer.write_all(&empty_times_row)?;
+    }
+
+    visual.finish()?;
+    times_writer.flush()?;
+    drop(times_writer);
+
+    write_temporal(&times_path, temporal_path, resolution, time_range)?;
+    remove_file(&times_path)?;
+
+    Ok(status)
+}
+
+fn write_temporal(
+    times_path: &Path,
+    temporal_path: &Path,
+    resolution: [usize; 2],
+    (min_time, max_time): (Duration, Duration),
+) -> IoResult<()> {
+    let [width, height] = resolution;
+
+    let mut reader = BufReader::new(File::open(times_path)?);
+    let mut temporal = PngRowWriter::<Rgba, _>::create(temporal_path, resolution)?;
+
+    let mut bytes = vec![0; width * NANOS_BYTES];
+    let mut row = vec![Rgba::TRANSPARENT; width];
+
+    for _ in 0..height {
+        reader.read_exact(&mut bytes)?;
+
+        for (pixel, nanos) in row.iter_mut().zip(bytes.chunks_exact(NANOS_BYTES)) {
+            let nanos = u64::from_le_bytes(nanos.try_into().expect("chunk is exactly eight bytes"));
+            *pixel = temporal_pixel(Duration::from_nanos(nanos), min_time, max_time);
+        }
+
+        temporal.write_row(&row)?;
+    }
+
+    temporal.finish()
+}
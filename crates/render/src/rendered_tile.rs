@@ -0,0 +1,10 @@
+use std::time::Duration;
+
+use antler_colour::Rgba;
+use antler_image::Tile;
+
+pub struct RenderedTile {
+    pub tile: Tile,
+    pub pixels: Vec<Rgba>,
+    pub times: Vec<Duration>,
+}
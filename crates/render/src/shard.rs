@@ -0,0 +1,265 @@
+use std::{
+    collections::BTreeSet,
+    fs::File,
+    io::{BufReader, BufWriter, Error as IoError, Read, Result as IoResult, Write},
+    path::Path,
+    time::Duration,
+};
+
+use antler_colour::Rgba;
+use antler_image::Tile;
+
+use crate::{
+    errors::ShardError,
+    render::compose_images,
+    render_output::{RenderOutput, RenderStatus},
+    rendered_tile::RenderedTile,
+};
+
+const MAGIC: [u8; 8] = *b"ANTLSHRD";
+const VERSION: u32 = 1;
+// Image names are short; a longer length field means the file is corrupt, so it is rejected before allocating.
+const MAX_NAME_LENGTH: usize = 4096;
+
+pub struct Shard {
+    pub name: String,
+    pub fingerprint: u64,
+    pub background: Rgba,
+    pub output_origin: [usize; 2],
+    pub output_resolution: [usize; 2],
+    pub tiles_total: usize,
+    pub status: RenderStatus,
+    pub tiles: Vec<RenderedTile>,
+}
+
+impl Shard {
+    pub fn save(&self, path: impl AsRef<Path>) -> IoResult<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&self.fingerprint.to_le_bytes())?;
+
+        write_usize(&mut writer, self.name.len())?;
+        writer.write_all(self.name.as_bytes())?;
+
+        write_rgba(&mut writer, self.background)?;
+        write_coord(&mut writer, self.output_origin)?;
+        write_coord(&mut writer, self.output_resolution)?;
+        write_usize(&mut writer, self.tiles_total)?;
+        writer.write_all(&[status_to_byte(self.status)])?;
+
+        write_usize(&mut writer, self.tiles.len())?;
+        for rendered in &self.tiles {
+            write_coord(&mut writer, rendered.tile.min)?;
+            write_coord(&mut writer, rendered.tile.max)?;
+
+            for pixel in &rendered.pixels {
+                write_rgba(&mut writer, *pixel)?;
+            }
+
+            for time in &rendered.times {
+                writer.write_all(&u64::try_from(time.as_nanos()).unwrap_or(u64::MAX).to_le_bytes())?;
+            }
+        }
+
+        writer.flush()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ShardError> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(ShardError::InvalidMagic);
+        }
+
+        let version = u32::from_le_bytes(read_array(&mut reader)?);
+        if version != VERSION {
+            return Err(ShardError::UnsupportedVersion(version));
+        }
+
+        let fingerprint = u64::from_le_bytes(read_array(&mut reader)?);
+
+        let name_length = read_usize(&mut reader)?;
+        if name_length > MAX_NAME_LENGTH {
+            return Err(ShardError::NameTooLong(name_length));
+        }
+
+        let mut name = vec![0; name_length];
+        reader.read_exact(&mut name)?;
+        let name = String::from_utf8(name).map_err(|_| ShardError::InvalidName)?;
+
+        let background = read_rgba(&mut reader)?;
+        let output_origin = read_coord(&mut reader)?;
+        let output_resolution = read_coord(&mut reader)?;
+        let tiles_total = read_usize(&mut reader)?;
+        let [status] = read_array(&mut reader)?;
+        let status = status_from_byte(status)?;
+
+        let tile_count = read_usize(&mut reader)?;
+        let mut tiles = Vec::with_capacity(tile_count.min(tiles_total));
+        for _ in 0..tile_count {
+            let min = read_coord(&mut reader)?;
+            let max = read_coord(&mut reader)?;
+
+            if min[0] >= max[0] || min[1] >= max[1] {
+                return Err(ShardError::InvalidTile { min, max });
+            }
+
+            let tile = Tile::new(min, max);
+
+            let pixels = (0..tile.num_pixels())
+                .map(|_| read_rgba(&mut reader))
+                .collect::<IoResult<Vec<_>>>()?;
+
+            let times = (0..tile.num_pixels())
+                .map(|_| read_array(&mut reader).map(|bytes| Duration::from_nanos(u64::from_le_bytes(bytes))))
+                .collect::<IoResult<Vec<_>>>()?;
+
+            tiles.push(RenderedTile { tile, pixels, times });
+        }
+
+        Ok(Self {
+            name,
+            fingerprint,
+            background,
+            output_origin,
+            output_resolution,
+            tiles_total,
+            status,
+            tiles,
+        })
+    }
+
+    pub fn merge(shards: Vec<Self>) -> Result<RenderOutput, ShardError> {
+        let Some(first) = shards.first() else {
+            return Err(ShardError::NoShards);
+        };
+
+        let name = first.name.clone();
+        let fingerprint = first.fingerprint;
+        let background = first.background;
+        let origin = first.output_origin;
+        let resolution = first.output_resolution;
+        let tiles_total = first.tiles_total;
+
+        let mut covered = BTreeSet::new();
+        let mut tiles = Vec::new();
+
+        for shard in shards {
+            if shard.name != name {
+                return Err(ShardError::NameMismatch {
+                    expected: name,
+                    found: shard.name,
+                });
+            }
+
+            if shard.fingerprint != fingerprint {
+                return Err(ShardError::FingerprintMismatch { name });
+            }
+
+            if shard.background != background
+                || shard.output_origin != origin
+                || shard.output_resolution != resolution
+                || shard.tiles_total != tiles_total
+            {
+                return Err(ShardError::LayoutMismatch { name });
+            }
+
+            if shard.status != RenderStatus::Complete {
+                return Err(ShardError::Incomplete { name });
+            }
+
+            for rendered in shard.tiles {
+                let min = rendered.tile.min;
+                let max = rendered.tile.max;
+
+                if min[0] < origin[0]
+                    || min[1] < origin[1]
+                    || max[0] > origin[0] + resolution[0]
+                    || max[1] > origin[1] + resolution[1]
+                {
+                    return Err(ShardError::InvalidTile { min, max });
+                }
+
+                if covered.insert(min) {
+                    tiles.push(rendered);
+                }
+            }
+        }
+
+        if covered.len() < tiles_total {
+            return Err(ShardError::MissingTiles {
+                name,
+                missing: tiles_total - covered.len(),
+                total: tiles_total,
+            });
+        }
+
+        let (visual, temporal) = compose_images(background, origin, resolution, &tiles);
+
+        Ok(RenderOutput {
+            visual,
+            temporal,
+            status: RenderStatus::Complete,
+        })
+    }
+}
+
+const fn status_to_byte(status: RenderStatus) -> u8 {
+    match status {
+        RenderStatus::Complete => 0,
+        RenderStatus::Cancelled => 1,
+        RenderStatus::TimedOut => 2,
+    }
+}
+
+const fn status_from_byte(byte: u8) -> Result<RenderStatus, ShardError> {
+    match byte {
+        0 => Ok(RenderStatus::Complete),
+        1 => Ok(RenderStatus::Cancelled),
+        2 => Ok(RenderStatus::TimedOut),
+        _ => Err(ShardError::InvalidStatus(byte)),
+    }
+}
+
+fn write_usize(writer: &mut impl Write, value: usize) -> IoResult<()> {
+    writer.write_all(&(value as u64).to_le_bytes())
+}
+
+fn write_coord(writer: &mut impl Write, coord: [usize; 2]) -> IoResult<()> {
+    write_usize(writer, coord[0])?;
+    write_usize(writer, coord[1])
+}
+
+fn write_rgba(writer: &mut impl Write, colour: Rgba) -> IoResult<()> {
+    for channel in [colour.red, colour.green, colour.blue, colour.alpha] {
+        writer.write_all(&channel.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> IoResult<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_usize(reader: &mut impl Read) -> IoResult<usize> {
+    usize::try_from(u64::from_le_bytes(read_array(reader)?)).map_err(IoError::other)
+}
+
+fn read_coord(reader: &mut impl Read) -> IoResult<[usize; 2]> {
+    Ok([read_usize(reader)?, read_usize(reader)?])
+}
+
+fn read_rgba(reader: &mut impl Read) -> IoResult<Rgba> {
+    Ok(Rgba::new(
+        f32::from_le_bytes(read_array(reader)?),
+        f32::from_le_bytes(read_array(reader)?),
+        f32::from_le_bytes(read_array(reader)?),
+        f32::from_le_bytes(read_array(reader)?),
+    ))
+}
@@ -0,0 +1,44 @@
+use indicatif::ProgressBar;
+
+use crate::{
+    progress::{Progress, ProgressReport},
+    utils::progress_bar,
+};
+
+pub struct TerminalProgress {
+    bar: ProgressBar,
+}
+
+impl Default for TerminalProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerminalProgress {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { bar: progress_bar(0) }
+    }
+}
+
+impl Progress for TerminalProgress {
+    #[inline]
+    fn start(&self, report: &ProgressReport) {
+        self.bar.set_length(report.tiles_total as u64);
+        self.bar.set_position(report.tiles_done as u64);
+        self.bar.reset_elapsed();
+        self.bar.reset_eta();
+    }
+
+    #[inline]
+    fn update(&self, report: &ProgressReport) {
+        self.bar.set_position(report.tiles_done as u64);
+    }
+
+    #[inline]
+    fn finish(&self, report: &ProgressReport) {
+        self.bar.set_position(report.tiles_done as u64);
+        self.bar.finish();
+    }
+}
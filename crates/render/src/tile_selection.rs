@@ -0,0 +1,35 @@
+use std::ops::Range;
+
+use antler_image::Tile;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TileSelection {
+    All,
+    Range(Range<usize>),
+    Shard { index: usize, count: usize },
+}
+
+impl TileSelection {
+    #[must_use]
+    #[inline]
+    pub fn range(&self, tiles_total: usize) -> Range<usize> {
+        match self {
+            Self::All => 0..tiles_total,
+            Self::Range(range) => range.start.min(tiles_total)..range.end.min(tiles_total),
+            Self::Shard { index, count } => {
+                assert!(*count > 0, "Shard count must be positive");
+                assert!(*index < *count, "Shard index must be less than the shard count");
+
+                (index * tiles_total / count)..((index + 1) * tiles_total / count)
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn select(&self, tiles: Vec<Tile>) -> Vec<Tile> {
+        let range = self.range(tiles.len());
+
+        tiles.into_iter().skip(range.start).take(range.len()).collect()
+    }
+}
@@ -1,5 +1,6 @@
 use antler_colour::Rgb;
 
+#[derive(Clone, Copy)]
 pub struct Emissive {
     pub colour: Rgb,
     pub intensity: f32,
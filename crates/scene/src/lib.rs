@@ -1,9 +1,11 @@
 mod emissive;
 mod object;
 mod resources;
+mod scatter;
 mod scene;
 
 pub use emissive::Emissive;
 pub use object::Object;
 pub use resources::Resources;
+pub use scatter::Scatter;
 pub use scene::Scene;
@@ -10,6 +10,7 @@ pub struct Object {
     pub emissive: Option<Emissive>,
     pub transform: Similarity3<f32>,
     pub inv_transform: Similarity3<f32>,
+    pub end_transform: Option<Similarity3<f32>>,
 }
 
 impl Object {
@@ -29,6 +30,44 @@ impl Object {
             emissive,
             transform,
             inv_transform,
+            end_transform: None,
         }
     }
+
+    // Instances share this object's resources, so only the transform is stored per copy. It is applied on top of
+    // this object's own transform, so any scale or reorientation given to the template carries over.
+    #[must_use]
+    #[inline]
+    pub fn instance(&self, transform: Similarity3<f32>) -> Self {
+        Self::new(
+            self.geometry_id,
+            self.shader_id,
+            self.material_id,
+            self.emissive,
+            transform * self.transform,
+        )
+        .with_end_transform(self.end_transform.map(|end_transform| transform * end_transform))
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn with_end_transform(mut self, end_transform: Option<Similarity3<f32>>) -> Self {
+        self.end_transform = end_transform;
+        self
+    }
+
+    // Returns the transform and its inverse at a time within the shutter interval.
+    #[must_use]
+    #[inline]
+    pub fn transforms_at(&self, time: f32) -> (Similarity3<f32>, Similarity3<f32>) {
+        let Some(end_transform) = self.end_transform else {
+            return (self.transform, self.inv_transform);
+        };
+
+        let isometry = self.transform.isometry.lerp_slerp(&end_transform.isometry, time);
+        let scaling = (end_transform.scaling() - self.transform.scaling()).mul_add(time, self.transform.scaling());
+        let transform = Similarity3::from_isometry(isometry, scaling);
+
+        (transform, transform.inverse())
+    }
 }
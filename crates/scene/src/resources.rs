@@ -55,12 +55,26 @@ impl Resources {
         id
     }
 
+    #[inline]
+    pub fn set_shader<S>(&mut self, id: ShaderId, shader: S)
+    where
+        S: Into<Shader>,
+    {
+        self.shaders[id.index()] = shader.into();
+    }
+
     #[must_use]
     #[inline]
     pub fn get_geometry(&self, id: GeometryId) -> &Geometry {
         &self.geometries[id.index()]
     }
 
+    #[must_use]
+    #[inline]
+    pub fn geometries(&self) -> &[Geometry] {
+        &self.geometries
+    }
+
     #[must_use]
     #[inline]
     pub fn get_material(&self, id: MaterialId) -> &Material {
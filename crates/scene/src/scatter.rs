@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use antler_geometry::{Aabb, Bounded, Geometry, Sampleable};
+use antler_image::RgbImage;
+use nalgebra::{Point2, Point3, Similarity3, Translation3, UnitQuaternion, Vector3};
+use rand::{RngExt, SeedableRng, rngs::SmallRng};
+
+const ATTEMPTS_PER_INSTANCE: usize = 30;
+
+// Distributes placements over a surface, rejecting candidates too close to an earlier one or in sparse regions.
+pub struct Scatter {
+    count: usize,
+    spacing: f32,
+    align: bool,
+    rotation: [f32; 2],
+    scale: [f32; 2],
+    density: Option<RgbImage>,
+    seed: u64,
+}
+
+impl Scatter {
+    #[must_use]
+    pub const fn new(count: usize, seed: u64) -> Self {
+        Self {
+            count,
+            spacing: 0.0,
+            align: true,
+            rotation: [0.0, std::f32::consts::TAU],
+            scale: [1.0, 1.0],
+            density: None,
+            seed,
+        }
+    }
+
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: f32) -> Self {
+        assert!(spacing >= 0.0, "Scatter spacing must be non-negative.");
+        self.spacing = spacing;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_align(mut self, align: bool) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[must_use]
+    pub fn with_rotation(mut self, rotation: [f32; 2]) -> Self {
+        assert!(rotation[0] <= rotation[1], "Scatter rotation range must be ordered.");
+        self.rotation = rotation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_scale(mut self, scale: [f32; 2]) -> Self {
+        assert!(
+            0.0 < scale[0] && scale[0] <= scale[1],
+            "Scatter scale range must be positive and ordered."
+        );
+        self.scale = scale;
+        self
+    }
+
+    #[must_use]
+    pub fn with_density(mut self, density: Option<RgbImage>) -> Self {
+        self.density = density;
+        self
+    }
+
+    // Placements may number fewer than requested when the spacing or density leaves too little room.
+    #[must_use]
+    pub fn transforms(&self, surface: &Geometry, surface_transform: &Similarity3<f32>) -> Vec<Similarity3<f32>> {
+        let mut rng = SmallRng::seed_from_u64(self.seed);
+        let bounds = surface.bounds().transform(surface_transform);
+
+        let mut grid = SpacingGrid::new(self.spacing);
+        let mut transforms = Vec::with_capacity(self.count);
+
+        for _ in 0..self.count * ATTEMPTS_PER_INSTANCE {
+            if transforms.len() == self.count {
+                break;
+            }
+
+            let sample = surface.sample(&mut rng).transform(surface_transform);
+
+            if rng.random::<f32>() >= self.density_at(&bounds, &sample.position) || !grid.insert(sample.position) {
+                continue;
+            }
+
+            let spin = UnitQuaternion::from_axis_angle(
+                &Vector3::z_axis(),
+                rng.random_range(self.rotation[0]..=self.rotation[1]),
+            );
+            let rotation = if self.align {
+                UnitQuaternion::rotation_between(&Vector3::z(), &sample.normal)
+                    .unwrap_or_else(|| UnitQuaternion::from_axis_angle(&Vector3::x_axis(), std::f32::consts::PI))
+                    * spin
+            } else {
+                spin
+            };
+            let scale = rng.random_range(self.scale[0]..=self.scale[1]);
+
+            transforms.push(Similarity3::from_parts(
+                Translation3::from(sample.position.coords),
+                rotation,
+                scale,
+            ));
+        }
+
+        transforms
+    }
+
+    // The density texture is projected straight down onto the surface's bounds in world space.
+    fn density_at(&self, bounds: &Aabb, position: &Point3<f32>) -> f32 {
+        let Some(density) = &self.density else {
+            return 1.0;
+        };
+
+        let extent = bounds.max - bounds.min;
+        let uv = Point2::new(
+            (position.x - bounds.min.x) / extent.x.max(f32::EPSILON),
+            (position.y - bounds.min.y) / extent.y.max(f32::EPSILON),
+        );
+
+        density
+            .sample_nearest(uv.map(|coord| coord.clamp(0.0, 1.0)))
+            .luminance()
+    }
+}
+
+// Buckets accepted points into cells one spacing wide, so only neighbouring cells need checking.
+struct SpacingGrid {
+    spacing: f32,
+    cells: HashMap<[i64; 3], Vec<Point3<f32>>>,
+}
+
+impl SpacingGrid {
+    fn new(spacing: f32) -> Self {
+        Self {
+            spacing,
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell(&self, position: &Point3<f32>) -> [i64; 3] {
+        [position.x, position.y, position.z].map(|coord| (coord / self.spacing).floor() as i64)
+    }
+
+    fn insert(&mut self, position: Point3<f32>) -> bool {
+        if self.spacing <= 0.0 {
+            return true;
+        }
+
+        let [x, y, z] = self.cell(&position);
+        let spacing_squared = self.spacing * self.spacing;
+
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let crowded = self.cells.get(&[x + dx, y + dy, z + dz]).is_some_and(|points| {
+                        points
+                            .iter()
+                            .any(|point| (point - position).norm_squared() < spacing_squared)
+                    });
+
+                    if crowded {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        self.cells.entry([x, y, z]).or_default().push(position);
+        true
+    }
+}
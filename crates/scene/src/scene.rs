@@ -1,18 +1,19 @@
 use antler_colour::Rgb;
-use antler_geometry::{Bounded, Bvh, Contact, Ray, Sample, Sampleable, Traceable, utils::hemisphere_direction};
+use antler_geometry::{Aabb, Bounded, Bvh, Contact, Ray, Sample, Sampleable, Traceable, utils::hemisphere_direction};
 use antler_id::ObjectId;
 use antler_light::{Emissive, Light, LightSample};
 use antler_material::Bsdf;
 use antler_settings::OcclusionSettings;
 use antler_shader::Appearance;
 use antler_skybox::{Constant, Sky, Skybox};
-use nalgebra::{Unit, Vector3};
+use nalgebra::{Similarity3, Unit, Vector3};
 use rand::Rng;
 
 use crate::{object::Object, resources::Resources};
 
 const MAX_VISIBILITY_HITS: usize = 16;
 const VISIBILITY_EPSILON: f32 = 1.0e-3;
+const MOTION_BOUND_STEPS: usize = 16;
 
 pub struct Scene {
     ambient: Rgb,
@@ -76,6 +77,26 @@ impl Scene {
         self.bvh = None;
     }
 
+    #[inline]
+    pub fn set_light(&mut self, light_id: ObjectId, light: Light) {
+        self.lights[light_id.index()] = light;
+    }
+
+    // Moving an object invalidates the BVH until the scene is built again.
+    #[inline]
+    pub fn set_object_transform(
+        &mut self,
+        object_id: ObjectId,
+        transform: Similarity3<f32>,
+        end_transform: Option<Similarity3<f32>>,
+    ) {
+        let object = &mut self.objects[object_id.index()];
+        object.transform = transform;
+        object.inv_transform = transform.inverse();
+        object.end_transform = end_transform;
+        self.bvh = None;
+    }
+
     #[must_use]
     #[inline]
     pub fn get_light(&self, light_id: ObjectId) -> &Light {
@@ -94,19 +115,52 @@ impl Scene {
         self.bvh.as_ref().expect("Scene BVH is not built")
     }
 
+    #[must_use]
+    #[inline]
+    pub fn bounds(&self) -> Aabb {
+        self.bvh().bounds()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn object_bounds(&self, resources: &Resources, object_id: ObjectId) -> Aabb {
+        let object = self.get_object(object_id);
+        let bounds = resources.get_geometry(object.geometry_id).bounds();
+
+        let Some(end_transform) = object.end_transform else {
+            return bounds.transform(&object.transform);
+        };
+
+        // Moving objects are bounded over their whole path. Between samples every point of the geometry
+        // strays from the chord joining its sampled positions by at most the sagitta of its rotation arc,
+        // plus the drift of its scaled offset, so padding by both keeps the union conservative.
+        let path = Aabb::union((0..=MOTION_BOUND_STEPS).map(|step| {
+            let (transform, _) = object.transforms_at(step as f32 / MOTION_BOUND_STEPS as f32);
+            bounds.transform(&transform)
+        }));
+
+        let radius = bounds.min.coords.abs().sup(&bounds.max.coords.abs()).norm();
+        let (start_scale, end_scale) = (object.transform.scaling(), end_transform.scaling());
+        let step_angle = object
+            .transform
+            .isometry
+            .rotation
+            .angle_to(&end_transform.isometry.rotation)
+            / MOTION_BOUND_STEPS as f32;
+        let padding = radius
+            * start_scale.max(end_scale).mul_add(
+                1.0 - (step_angle * 0.5).cos(),
+                (end_scale - start_scale).abs() / MOTION_BOUND_STEPS as f32,
+            );
+
+        let padding = Vector3::repeat(padding);
+        Aabb::new(path.min - padding, path.max + padding)
+    }
+
     pub fn build(&mut self, resources: &Resources) {
-        let items = self
-            .objects
-            .iter()
-            .enumerate()
-            .map(|(index, object)| {
-                let bounds = resources
-                    .get_geometry(object.geometry_id)
-                    .bounds()
-                    .transform(&object.transform);
-
-                (bounds, ObjectId::new(index))
-            })
+        let items = (0..self.objects.len())
+            .map(ObjectId::new)
+            .map(|object_id| (self.object_bounds(resources, object_id), object_id))
             .collect();
 
         self.bvh = Some(Bvh::new(items));
@@ -190,7 +244,7 @@ impl Scene {
                 break;
             }
 
-            ray = Ray::from_offset(contact.position, contact.normal, ray.direction);
+            ray = Ray::from_offset(contact.position, contact.normal, ray.direction).with_time(ray.time);
         }
 
         visibility
@@ -237,9 +291,10 @@ impl Scene {
         max_distance: f32,
     ) -> Option<f32> {
         let object = self.get_object(object_id);
-        let object_ray = world_ray.transform(&object.inv_transform);
+        let (transform, inv_transform) = object.transforms_at(world_ray.time);
+        let object_ray = world_ray.transform(&inv_transform);
 
-        let scale = object.transform.scaling();
+        let scale = transform.scaling();
         let object_max_distance = max_distance / scale;
 
         let geometry = resources.get_geometry(object.geometry_id);
@@ -259,15 +314,16 @@ impl Scene {
         max_distance: f32,
     ) -> Option<Contact> {
         let object = self.get_object(object_id);
-        let object_ray = world_ray.transform(&object.inv_transform);
+        let (transform, inv_transform) = object.transforms_at(world_ray.time);
+        let object_ray = world_ray.transform(&inv_transform);
 
-        let scale = object.transform.scaling();
+        let scale = transform.scaling();
         let object_max_distance = max_distance / scale;
 
         let geometry = resources.get_geometry(object.geometry_id);
         geometry
             .intersection(&object_ray, object_max_distance)
-            .map(|contact| contact.transform(&object.transform, world_ray.origin))
+            .map(|contact| contact.transform(&transform, world_ray.origin))
             .filter(|contact| contact.distance < max_distance)
     }
 
@@ -311,7 +367,8 @@ impl Scene {
                     return;
                 }
 
-                let shadow_ray = Ray::from_offset(contact.position, contact.normal, sample.direction);
+                let shadow_ray =
+                    Ray::from_offset(contact.position, contact.normal, sample.direction).with_time(world_ray.time);
                 let shadow_distance = sample.distance - (shadow_ray.origin - contact.position).norm();
 
                 if shadow_distance > 0.0 {
@@ -341,13 +398,14 @@ impl Scene {
             };
 
             let geometry = resources.get_geometry(emissive_object.geometry_id);
+            let (emissive_transform, _) = emissive_object.transforms_at(world_ray.time);
             let radiance = emissive.colour * emissive.intensity;
 
             let samples = emissive.samples.max(1);
             let mut light_total = Rgb::BLACK;
 
             for _ in 0..samples {
-                let surface_sample = geometry.sample(rng).transform(&emissive_object.transform);
+                let surface_sample = geometry.sample(rng).transform(&emissive_transform);
 
                 let Some(light_sample) = area_light_sample(contact, &surface_sample, radiance) else {
                     continue;
@@ -357,7 +415,8 @@ impl Scene {
                     continue;
                 }
 
-                let shadow_ray = Ray::from_offset(contact.position, contact.normal, light_sample.direction);
+                let shadow_ray = Ray::from_offset(contact.position, contact.normal, light_sample.direction)
+                    .with_time(world_ray.time);
                 let shadow_distance = light_sample.distance - (shadow_ray.origin - contact.position).norm() - 1.0e-4;
 
                 if shadow_distance > 0.0 {
@@ -379,7 +438,7 @@ impl Scene {
 
     #[must_use]
     #[inline]
-    pub fn occlusion<R: Rng>(&self, rng: &mut R, resources: &Resources, contact: &mut Contact) -> f32 {
+    pub fn occlusion<R: Rng>(&self, rng: &mut R, resources: &Resources, world_ray: &Ray, contact: &mut Contact) -> f32 {
         let Some(ref ao) = self.occlusion else {
             return 1.0;
         };
@@ -392,7 +451,7 @@ impl Scene {
 
         for _ in 0..ao.samples {
             let direction = hemisphere_direction(rng, contact.normal, contact.tangent(), contact.bi_tangent());
-            let ray = Ray::from_offset(contact.position, contact.normal, direction);
+            let ray = Ray::from_offset(contact.position, contact.normal, direction).with_time(world_ray.time);
 
             if let Some((_object_id, distance)) = self.distance(resources, &ray, ao.distance) {
                 let proximity = 1.0 - (distance / ao.distance).clamp(0.0, 1.0);
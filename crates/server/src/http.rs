@@ -0,0 +1,140 @@
+use std::{
+    fmt::Display,
+    io::{BufRead, BufReader, Error as IoError, ErrorKind, Read, Result as IoResult, Write},
+};
+
+use serde::Serialize;
+use serde_json::json;
+
+const MAX_HEADER_COUNT: usize = 64;
+const MAX_LINE_LENGTH: usize = 8 * 1024;
+const MAX_BODY_SIZE: usize = 64 * 1024 * 1024;
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn read(stream: impl Read) -> IoResult<Self> {
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        read_line(&mut reader, &mut request_line)?;
+
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(invalid_data(format!(
+                "Malformed request line '{}'",
+                request_line.trim_end()
+            )));
+        };
+        let path = target.split_once('?').map_or(target, |(path, _query)| path);
+
+        let mut content_length = 0;
+        for _ in 0..=MAX_HEADER_COUNT {
+            let mut header = String::new();
+            read_line(&mut reader, &mut header)?;
+
+            let header = header.trim_end();
+            if header.is_empty() {
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body)?;
+
+                return Ok(Self {
+                    method: method.to_uppercase(),
+                    path: path.to_owned(),
+                    body,
+                });
+            }
+
+            if let Some((name, value)) = header.split_once(':')
+                && name.trim().eq_ignore_ascii_case("content-length")
+            {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid_data(format!("Invalid Content-Length '{}'", value.trim())))?;
+
+                if content_length > MAX_BODY_SIZE {
+                    return Err(invalid_data(format!(
+                        "Request body of {content_length} bytes exceeds the {MAX_BODY_SIZE} byte limit"
+                    )));
+                }
+            }
+        }
+
+        Err(invalid_data(format!(
+            "Request has more than {MAX_HEADER_COUNT} headers"
+        )))
+    }
+}
+
+pub struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Response {
+    pub fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: serde_json::to_vec_pretty(value).expect("response values always serialise"),
+        }
+    }
+
+    pub const fn png(bytes: Vec<u8>) -> Self {
+        Self {
+            status: 200,
+            content_type: "image/png",
+            body: bytes,
+        }
+    }
+
+    pub fn error(status: u16, message: impl Display) -> Self {
+        Self::json(status, &json!({ "error": message.to_string() }))
+    }
+
+    pub fn write(&self, mut stream: impl Write) -> IoResult<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            reason(self.status),
+            self.content_type,
+            self.body.len()
+        )?;
+        stream.write_all(&self.body)?;
+        stream.flush()
+    }
+}
+
+const fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        _ => "Internal Server Error",
+    }
+}
+
+fn read_line(reader: &mut impl BufRead, line: &mut String) -> IoResult<()> {
+    let length = reader.take(MAX_LINE_LENGTH as u64).read_line(line)?;
+
+    if length == MAX_LINE_LENGTH && !line.ends_with('\n') {
+        return Err(invalid_data(format!(
+            "Request line or header exceeds the {MAX_LINE_LENGTH} byte limit"
+        )));
+    }
+    Ok(())
+}
+
+fn invalid_data(message: String) -> IoError {
+    IoError::new(ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,245 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use antler_render::{CancelToken, ProgressReport, RenderStatus};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    Queued,
+    Running,
+    Complete,
+    Cancelled,
+    TimedOut,
+    Failed,
+}
+
+impl JobState {
+    #[must_use]
+    #[inline]
+    pub const fn is_finished(self) -> bool {
+        !matches!(self, Self::Queued | Self::Running)
+    }
+}
+
+impl From<RenderStatus> for JobState {
+    #[inline]
+    fn from(status: RenderStatus) -> Self {
+        match status {
+            RenderStatus::Complete => Self::Complete,
+            RenderStatus::Cancelled => Self::Cancelled,
+            RenderStatus::TimedOut => Self::TimedOut,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageStats {
+    pub name: String,
+    pub status: JobState,
+    pub resolution: [usize; 2],
+    pub super_samples: usize,
+    pub tiles_done: usize,
+    pub samples_done: u64,
+    pub render_secs: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgressSummary {
+    pub tiles_done: usize,
+    pub tiles_total: usize,
+    pub samples_done: u64,
+    pub samples_total: u64,
+    pub fraction: f32,
+    pub elapsed_secs: f64,
+    pub eta_secs: Option<f64>,
+}
+
+impl From<&ProgressReport> for ProgressSummary {
+    #[inline]
+    fn from(report: &ProgressReport) -> Self {
+        Self {
+            tiles_done: report.tiles_done,
+            tiles_total: report.tiles_total,
+            samples_done: report.samples_done,
+            samples_total: report.samples_total,
+            fraction: report.fraction(),
+            elapsed_secs: report.elapsed.as_secs_f64(),
+            eta_secs: report.eta().as_ref().map(Duration::as_secs_f64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobSummary {
+    pub id: u64,
+    pub state: JobState,
+    pub images_done: usize,
+    pub images_total: usize,
+    pub current_image: Option<String>,
+    pub progress: Option<ProgressSummary>,
+    pub images: Vec<String>,
+    pub queued_secs: f64,
+    pub running_secs: Option<f64>,
+    pub error: Option<String>,
+}
+
+struct JobRecord {
+    state: JobState,
+    started: Option<Instant>,
+    finished: Option<Instant>,
+    images_total: usize,
+    current_image: Option<String>,
+    progress: Option<ProgressReport>,
+    stats: Vec<ImageStats>,
+    images: BTreeMap<String, Vec<u8>>,
+    error: Option<String>,
+}
+
+pub struct Job {
+    pub id: u64,
+    pub cancel: CancelToken,
+    submitted: Instant,
+    record: Mutex<JobRecord>,
+}
+
+impl Job {
+    #[must_use]
+    #[inline]
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            cancel: CancelToken::new(),
+            submitted: Instant::now(),
+            record: Mutex::new(JobRecord {
+                state: JobState::Queued,
+                started: None,
+                finished: None,
+                images_total: 0,
+                current_image: None,
+                progress: None,
+                stats: Vec::new(),
+                images: BTreeMap::new(),
+                error: None,
+            }),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn state(&self) -> JobState {
+        self.record().state
+    }
+
+    #[must_use]
+    pub fn summary(&self) -> JobSummary {
+        let record = self.record();
+        let started = record.started.unwrap_or_else(Instant::now);
+
+        JobSummary {
+            id: self.id,
+            state: record.state,
+            images_done: record.stats.len(),
+            images_total: record.images_total,
+            current_image: record.current_image.clone(),
+            progress: record.progress.as_ref().map(ProgressSummary::from),
+            images: record.images.keys().cloned().collect(),
+            queued_secs: started.duration_since(self.submitted).as_secs_f64(),
+            running_secs: record.started.map(|started| {
+                record
+                    .finished
+                    .unwrap_or_else(Instant::now)
+                    .duration_since(started)
+                    .as_secs_f64()
+            }),
+            error: record.error.clone(),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn progress(&self) -> Option<ProgressReport> {
+        self.record().progress
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn stats(&self) -> Vec<ImageStats> {
+        self.record().stats.clone()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn image(&self, name: &str) -> Option<Vec<u8>> {
+        self.record().images.get(name).cloned()
+    }
+
+    pub fn cancel(&self) -> bool {
+        let mut record = self.record();
+
+        match record.state {
+            JobState::Queued => {
+                record.state = JobState::Cancelled;
+                record.finished = Some(Instant::now());
+                true
+            }
+            JobState::Running => {
+                drop(record);
+                self.cancel.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn start(&self) -> bool {
+        let mut record = self.record();
+
+        if record.state != JobState::Queued {
+            return false;
+        }
+
+        record.state = JobState::Running;
+        record.started = Some(Instant::now());
+        true
+    }
+
+    pub fn begin_image(&self, name: &str, images_total: usize) {
+        let mut record = self.record();
+        record.images_total = images_total;
+        record.current_image = Some(name.to_owned());
+        record.progress = None;
+    }
+
+    pub fn report(&self, report: &ProgressReport) {
+        self.record().progress = Some(*report);
+    }
+
+    pub fn finish_image(&self, stats: ImageStats, images: impl IntoIterator<Item = (String, Vec<u8>)>) {
+        let mut record = self.record();
+        record.stats.push(stats);
+        record.images.extend(images);
+    }
+
+    pub fn add_images(&self, images: impl IntoIterator<Item = (String, Vec<u8>)>) {
+        self.record().images.extend(images);
+    }
+
+    pub fn finish(&self, state: JobState, error: Option<String>) {
+        assert!(state.is_finished(), "Jobs must finish in a terminal state.");
+
+        let mut record = self.record();
+        record.state = state;
+        record.finished = Some(Instant::now());
+        record.current_image = None;
+        record.error = error;
+    }
+
+    fn record(&self) -> MutexGuard<'_, JobRecord> {
+        self.record.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
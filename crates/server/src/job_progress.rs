@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use antler_render::{Progress, ProgressReport};
+
+use crate::job::Job;
+
+pub struct JobProgress {
+    job: Arc<Job>,
+}
+
+impl JobProgress {
+    #[must_use]
+    #[inline]
+    pub const fn new(job: Arc<Job>) -> Self {
+        Self { job }
+    }
+}
+
+impl Progress for JobProgress {
+    #[inline]
+    fn start(&self, report: &ProgressReport) {
+        self.job.report(report);
+    }
+
+    #[inline]
+    fn update(&self, report: &ProgressReport) {
+        self.job.report(report);
+    }
+
+    #[inline]
+    fn finish(&self, report: &ProgressReport) {
+        self.job.report(report);
+    }
+}
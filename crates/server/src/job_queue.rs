@@ -0,0 +1,268 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    error::Error,
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::{
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Instant,
+};
+
+use antler_camera::Camera;
+use antler_config::Manifest;
+use antler_image::RgbaImage;
+use antler_parameters::SimulationParameters;
+use antler_render::{RenderOptions, RenderStatus, render_image};
+use antler_scene::Scene;
+use antler_settings::{ImageSettings, Sequence};
+
+use crate::{
+    job::{ImageStats, Job, JobState},
+    job_progress::JobProgress,
+};
+
+#[derive(Default)]
+pub struct JobQueue {
+    next_id: AtomicU64,
+    jobs: Mutex<BTreeMap<u64, Arc<Job>>>,
+    pending: Mutex<Pending>,
+    available: Condvar,
+}
+
+#[derive(Default)]
+struct Pending {
+    entries: VecDeque<(Arc<Job>, Manifest)>,
+    closed: bool,
+}
+
+impl JobQueue {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn submit(&self, manifest: Manifest) -> Arc<Job> {
+        let job = Arc::new(Job::new(self.next_id.fetch_add(1, Ordering::Relaxed) + 1));
+
+        lock(&self.jobs).insert(job.id, Arc::clone(&job));
+        lock(&self.pending).entries.push_back((Arc::clone(&job), manifest));
+        self.available.notify_one();
+
+        job
+    }
+
+    #[must_use]
+    pub fn job(&self, id: u64) -> Option<Arc<Job>> {
+        lock(&self.jobs).get(&id).cloned()
+    }
+
+    #[must_use]
+    pub fn jobs(&self) -> Vec<Arc<Job>> {
+        lock(&self.jobs).values().cloned().collect()
+    }
+
+    // Only finished jobs can be removed; unfinished ones must be cancelled first.
+    pub fn remove(&self, id: u64) -> Option<Arc<Job>> {
+        let mut jobs = lock(&self.jobs);
+
+        if !jobs.get(&id)?.state().is_finished() {
+            return None;
+        }
+        jobs.remove(&id)
+    }
+
+    // Stops the worker once its current job ends, cancelling that job and everything still queued.
+    pub fn close(&self) {
+        let mut pending = lock(&self.pending);
+        pending.closed = true;
+        pending.entries.clear();
+        drop(pending);
+
+        for job in self.jobs() {
+            job.cancel();
+        }
+        self.available.notify_all();
+    }
+
+    pub fn spawn_worker(self: &Arc<Self>) -> JoinHandle<()> {
+        let queue = Arc::clone(self);
+
+        thread::spawn(move || {
+            while let Some((job, manifest)) = queue.next() {
+                if !job.start() {
+                    continue;
+                }
+
+                match catch_unwind(AssertUnwindSafe(|| run_job(&job, manifest))) {
+                    Ok(Ok(state)) => job.finish(state, None),
+                    Ok(Err(err)) => job.finish(JobState::Failed, Some(err.to_string())),
+                    Err(_) => job.finish(JobState::Failed, Some("Render panicked".to_owned())),
+                }
+            }
+        })
+    }
+
+    fn next(&self) -> Option<(Arc<Job>, Manifest)> {
+        let mut pending = lock(&self.pending);
+
+        loop {
+            if pending.closed {
+                return None;
+            }
+            if let Some(entry) = pending.entries.pop_front() {
+                return Some(entry);
+            }
+
+            pending = self.available.wait(pending).unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+fn run_job(job: &Arc<Job>, manifest: Manifest) -> Result<JobState, Box<dyn Error>> {
+    let (mut parameters, animation) = manifest.build_animated()?;
+    let frames = animation
+        .frames()
+        .map_or_else(|| vec![None], |frames| frames.map(Some).collect());
+
+    let images_total = frames.len()
+        * parameters
+            .scenes
+            .values()
+            .flat_map(|scene| scene.captures.values())
+            .map(|capture| capture.images.len() * capture.cameras.len())
+            .sum::<usize>();
+
+    for frame in frames {
+        if let Some(frame) = frame {
+            animation.apply(frame, &mut parameters)?;
+        }
+
+        let state = render_scenes(job, &parameters, frame, images_total)?;
+        if state != JobState::Complete {
+            return Ok(state);
+        }
+    }
+
+    Ok(JobState::Complete)
+}
+
+fn render_scenes(
+    job: &Arc<Job>,
+    parameters: &SimulationParameters,
+    frame: Option<usize>,
+    images_total: usize,
+) -> Result<JobState, Box<dyn Error>> {
+    for (scene_name, scene) in &parameters.scenes {
+        for (capture_name, capture) in &scene.captures {
+            for (image_name, image) in &capture.images {
+                let name = format!("{scene_name}-{capture_name}-{image_name}");
+                let name = match frame {
+                    Some(frame) => Sequence::frame_name(&name, frame),
+                    None => name,
+                };
+
+                let Some(sequence) = capture.sequence else {
+                    let (status, _) = render_frame(
+                        job,
+                        parameters,
+                        &scene.scene,
+                        &capture.cameras[0],
+                        image,
+                        &name,
+                        images_total,
+                    )?;
+                    if status != RenderStatus::Complete {
+                        return Ok(status.into());
+                    }
+                    continue;
+                };
+
+                let mut orbit_frames = Vec::with_capacity(capture.cameras.len());
+                for (orbit_frame, camera) in capture.cameras.iter().enumerate() {
+                    let frame_name = Sequence::frame_name(&name, orbit_frame);
+                    let (status, visual) =
+                        render_frame(job, parameters, &scene.scene, camera, image, &frame_name, images_total)?;
+                    if status != RenderStatus::Complete {
+                        return Ok(status.into());
+                    }
+                    orbit_frames.push(visual);
+                }
+
+                let mut assembled = Vec::new();
+                if let Some(animation) = sequence.animation {
+                    let mut bytes = Vec::new();
+                    RgbaImage::write_animated_png(&orbit_frames, animation.fps, animation.plays, &mut bytes)?;
+                    assembled.push((format!("{}.png", Sequence::animation_name(&name)), bytes));
+                }
+                if let Some(contact_sheet) = sequence.contact_sheet {
+                    let mut bytes = Vec::new();
+                    RgbaImage::contact_sheet(&orbit_frames, contact_sheet.columns, image.background)
+                        .write_png(&mut bytes)?;
+                    assembled.push((format!("{}.png", Sequence::contact_sheet_name(&name)), bytes));
+                }
+                job.add_images(assembled);
+            }
+        }
+    }
+
+    Ok(JobState::Complete)
+}
+
+fn render_frame(
+    job: &Arc<Job>,
+    parameters: &SimulationParameters,
+    scene: &Scene,
+    camera: &Camera,
+    image: &ImageSettings,
+    name: &str,
+    images_total: usize,
+) -> Result<(RenderStatus, RgbaImage), Box<dyn Error>> {
+    job.begin_image(name, images_total);
+
+    let options = RenderOptions::default()
+        .with_progress(JobProgress::new(Arc::clone(job)))
+        .with_cancel(job.cancel.clone());
+
+    let start = Instant::now();
+    let output = render_image(
+        image,
+        &parameters.lighting_settings,
+        &parameters.probe_settings,
+        camera,
+        &parameters.resources,
+        scene,
+        &options,
+    );
+    let render_secs = start.elapsed().as_secs_f64();
+
+    let mut visual = Vec::new();
+    output.visual.write_png(&mut visual)?;
+    let mut temporal = Vec::new();
+    output.temporal.write_png(&mut temporal)?;
+
+    let report = job.progress();
+
+    job.finish_image(
+        ImageStats {
+            name: name.to_owned(),
+            status: output.status.into(),
+            resolution: output.visual.size(),
+            super_samples: image.super_samples,
+            tiles_done: report.map_or(0, |report| report.tiles_done),
+            samples_done: report.map_or(0, |report| report.samples_done),
+            render_secs,
+        },
+        [
+            (format!("{name}.png"), visual),
+            (format!("{name}-temporal.png"), temporal),
+        ],
+    );
+
+    Ok((output.status, output.visual))
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
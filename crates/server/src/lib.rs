@@ -0,0 +1,11 @@
+mod http;
+mod job;
+mod job_progress;
+mod job_queue;
+mod server;
+mod shutdown_handle;
+
+pub use job::{ImageStats, Job, JobState, JobSummary, ProgressSummary};
+pub use job_queue::JobQueue;
+pub use server::Server;
+pub use shutdown_handle::ShutdownHandle;
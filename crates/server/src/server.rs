@@ -0,0 +1,174 @@
+use std::{
+    io::{Error as IoError, Result as IoResult},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    path::PathBuf,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use antler_config::Manifest;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    http::{Request, Response},
+    job::{Job, JobSummary},
+    job_queue::JobQueue,
+    shutdown_handle::ShutdownHandle,
+};
+
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum Submission {
+    Manifest(String),
+    Path(PathBuf),
+}
+
+pub struct Server {
+    listener: TcpListener,
+    queue: Arc<JobQueue>,
+    shutdown: ShutdownHandle,
+}
+
+impl Server {
+    pub fn bind(address: impl ToSocketAddrs) -> IoResult<Self> {
+        let listener = TcpListener::bind(address)?;
+        let shutdown = ShutdownHandle::new(listener.local_addr()?);
+
+        Ok(Self {
+            listener,
+            queue: Arc::new(JobQueue::new()),
+            shutdown,
+        })
+    }
+
+    pub fn local_addr(&self) -> IoResult<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn queue(&self) -> &Arc<JobQueue> {
+        &self.queue
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    // Serves until the shutdown handle is triggered, then cancels outstanding jobs and waits for the worker.
+    pub fn run(self) -> IoResult<()> {
+        let worker = self.queue.spawn_worker();
+
+        for stream in self.listener.incoming() {
+            if self.shutdown.is_requested() {
+                break;
+            }
+            let Ok(stream) = stream else {
+                continue;
+            };
+
+            let queue = Arc::clone(&self.queue);
+            thread::spawn(move || handle_connection(stream, &queue));
+        }
+
+        self.queue.close();
+        if worker.join().is_err() {
+            return Err(IoError::other("Render worker panicked"));
+        }
+
+        Ok(())
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, queue: &JobQueue) {
+    let response = match stream
+        .set_read_timeout(Some(READ_TIMEOUT))
+        .and_then(|()| Request::read(&mut stream))
+    {
+        Ok(request) => route(&request, queue),
+        Err(err) => Response::error(400, err),
+    };
+
+    // The client may already have hung up, in which case there is no one left to tell.
+    let _ = response.write(&mut stream);
+}
+
+fn route(request: &Request, queue: &JobQueue) -> Response {
+    let segments = request.path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["jobs"]) => list_jobs(queue),
+        ("POST", ["jobs"]) => submit_job(queue, &request.body),
+        ("GET", ["jobs", id]) => with_job(queue, id, |job| Response::json(200, &job.summary())),
+        ("GET", ["jobs", id, "stats"]) => with_job(queue, id, |job| Response::json(200, &job.stats())),
+        ("GET", ["jobs", id, "images", name]) => with_job(queue, id, |job| {
+            job.image(name).map_or_else(
+                || Response::error(404, format!("Job {} has no image '{name}'", job.id)),
+                Response::png,
+            )
+        }),
+        ("POST", ["jobs", id, "cancel"]) => with_job(queue, id, |job| {
+            if job.cancel() {
+                Response::json(200, &job.summary())
+            } else {
+                Response::error(409, format!("Job {} has already finished", job.id))
+            }
+        }),
+        // Deleting an unfinished job cancels it; deleting a finished one drops it and its images.
+        ("DELETE", ["jobs", id]) => with_job(queue, id, |job| {
+            if !job.cancel() {
+                queue.remove(job.id);
+            }
+            Response::json(200, &job.summary())
+        }),
+        (_, ["jobs", ..]) => Response::error(405, format!("{} is not supported on {}", request.method, request.path)),
+        _ => Response::error(404, format!("No route for {}", request.path)),
+    }
+}
+
+fn list_jobs(queue: &JobQueue) -> Response {
+    let jobs = queue
+        .jobs()
+        .iter()
+        .map(|job| job.summary())
+        .collect::<Vec<JobSummary>>();
+    Response::json(200, &jobs)
+}
+
+fn submit_job(queue: &JobQueue, body: &[u8]) -> Response {
+    let submission = match serde_json::from_slice::<Submission>(body) {
+        Ok(submission) => submission,
+        Err(err) => {
+            return Response::error(
+                400,
+                format!("Expected {{\"manifest\": \"...\"}} or {{\"path\": \"...\"}}: {err}"),
+            );
+        }
+    };
+
+    let manifest = match submission {
+        Submission::Manifest(contents) => Manifest::parse(&contents),
+        Submission::Path(path) => Manifest::load(path),
+    };
+
+    match manifest {
+        Ok(manifest) => {
+            let job = queue.submit(manifest);
+            Response::json(201, &json!({ "id": job.id }))
+        }
+        Err(err) => Response::error(400, format!("Invalid manifest: {err}")),
+    }
+}
+
+fn with_job(queue: &JobQueue, id: &str, handler: impl FnOnce(&Job) -> Response) -> Response {
+    id.parse().ok().and_then(|id| queue.job(id)).map_or_else(
+        || Response::error(404, format!("No job with id '{id}'")),
+        |job| handler(&job),
+    )
+}
@@ -0,0 +1,43 @@
+use std::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    requested: Arc<AtomicBool>,
+    address: SocketAddr,
+}
+
+impl ShutdownHandle {
+    pub(crate) fn new(address: SocketAddr) -> Self {
+        Self {
+            requested: Arc::new(AtomicBool::new(false)),
+            address,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    pub fn shutdown(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+
+        // The accept loop only looks at the flag between connections, so knock once to wake it.
+        let mut address = self.address;
+        if address.ip().is_unspecified() {
+            address.set_ip(if address.is_ipv4() {
+                Ipv4Addr::LOCALHOST.into()
+            } else {
+                Ipv6Addr::LOCALHOST.into()
+            });
+        }
+        let _ = TcpStream::connect(address);
+    }
+}
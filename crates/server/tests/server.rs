@@ -0,0 +1,224 @@
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use antler_server::{Server, ShutdownHandle};
+use serde_json::{Value, json};
+
+const POLL_TIMEOUT: Duration = Duration::from_mins(1);
+
+struct TestServer {
+    address: SocketAddr,
+    shutdown: ShutdownHandle,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TestServer {
+    fn start() -> Self {
+        let server = Server::bind("127.0.0.1:0").expect("bind to a free localhost port");
+        let address = server.local_addr().expect("bound address");
+        let shutdown = server.shutdown_handle();
+        let thread = thread::spawn(move || server.run().expect("server runs until shut down"));
+
+        Self {
+            address,
+            shutdown,
+            thread: Some(thread),
+        }
+    }
+
+    fn raw(&self, request: &[u8]) -> (u16, Vec<u8>) {
+        let mut stream = TcpStream::connect(self.address).expect("connect to the server");
+        stream.write_all(request).expect("send the request");
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).expect("read the response");
+
+        let split = response
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .expect("response has a header block");
+        let head = String::from_utf8_lossy(&response[..split]);
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .expect("response has a status code");
+
+        (status, response[split + 4..].to_vec())
+    }
+
+    fn request(&self, method: &str, path: &str, body: Option<&Value>) -> (u16, Vec<u8>) {
+        let body = body.map(Value::to_string).unwrap_or_default();
+        self.raw(
+            format!(
+                "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+            .as_bytes(),
+        )
+    }
+
+    fn json(&self, method: &str, path: &str, body: Option<&Value>) -> (u16, Value) {
+        let (status, body) = self.request(method, path, body);
+        (status, serde_json::from_slice(&body).expect("response body is JSON"))
+    }
+
+    fn submit(&self, manifest: &str) -> u64 {
+        let (status, body) = self.json("POST", "/jobs", Some(&json!({ "manifest": manifest })));
+        assert_eq!(status, 201, "{body}");
+        body["id"].as_u64().expect("submission returns a job id")
+    }
+
+    fn wait_until_finished(&self, id: u64) -> Value {
+        let start = Instant::now();
+
+        loop {
+            let (status, job) = self.json("GET", &format!("/jobs/{id}"), None);
+            assert_eq!(status, 200, "{job}");
+
+            if !matches!(job["state"].as_str(), Some("queued" | "running")) {
+                return job;
+            }
+            assert!(start.elapsed() < POLL_TIMEOUT, "Job {id} did not finish in time");
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.shutdown.shutdown();
+        if let Some(thread) = self.thread.take() {
+            let joined = thread.join();
+            if !thread::panicking() {
+                joined.expect("server thread exits cleanly");
+            }
+        }
+    }
+}
+
+fn manifest(resolution: usize, super_samples: usize) -> String {
+    format!(
+        r#"(
+  scenes: {{
+    "scene": (
+      ambient: 0x333333,
+      skybox: Constant ( colour: 0x99BFFF ),
+      occlusion: None,
+      lights: [],
+      objects: [
+        (
+          geometry: Sphere ( ),
+          material: Opaque,
+          shader: Solid ( colour: 0xFF8019 ),
+        ),
+      ],
+      captures: {{
+        "main": (
+          camera: Perspective ( position: (0.0, -5.0, 0.0) ),
+          images: {{
+            "image": ( resolution: ({resolution}, {resolution}), super_samples: {super_samples} ),
+          }},
+        ),
+      }},
+    ),
+  }},
+)"#
+    )
+}
+
+#[test]
+fn submitted_job_renders_images_and_stats() {
+    let server = TestServer::start();
+    let id = server.submit(&manifest(8, 1));
+
+    let job = server.wait_until_finished(id);
+    assert_eq!(job["state"], "complete", "{job}");
+    assert_eq!(job["images_done"], 1);
+
+    let (status, jobs) = server.json("GET", "/jobs", None);
+    assert_eq!(status, 200);
+    assert_eq!(jobs.as_array().map(Vec::len), Some(1));
+    assert_eq!(jobs[0]["id"], id);
+
+    let (status, stats) = server.json("GET", &format!("/jobs/{id}/stats"), None);
+    assert_eq!(status, 200);
+    assert_eq!(stats[0]["name"], "scene-main-image");
+    assert_eq!(stats[0]["resolution"], json!([8, 8]));
+    assert_eq!(stats[0]["samples_done"], 64);
+
+    let (status, png) = server.request("GET", &format!("/jobs/{id}/images/scene-main-image.png"), None);
+    assert_eq!(status, 200);
+    assert!(png.starts_with(b"\x89PNG"));
+
+    let (status, _) = server.request("GET", &format!("/jobs/{id}/images/missing.png"), None);
+    assert_eq!(status, 404);
+}
+
+#[test]
+fn jobs_can_be_cancelled_and_deleted() {
+    let server = TestServer::start();
+    let running = server.submit(&manifest(2048, 8));
+    let queued = server.submit(&manifest(8, 1));
+
+    let (status, job) = server.json("POST", &format!("/jobs/{queued}/cancel"), None);
+    assert_eq!(status, 200);
+    assert_eq!(job["state"], "cancelled");
+
+    let (status, _) = server.json("POST", &format!("/jobs/{queued}/cancel"), None);
+    assert_eq!(status, 409);
+
+    let (status, _) = server.json("DELETE", &format!("/jobs/{running}"), None);
+    assert_eq!(status, 200);
+    let job = server.wait_until_finished(running);
+    assert_eq!(job["state"], "cancelled", "{job}");
+
+    let (status, _) = server.json("DELETE", &format!("/jobs/{running}"), None);
+    assert_eq!(status, 200);
+    let (status, _) = server.json("GET", &format!("/jobs/{running}"), None);
+    assert_eq!(status, 404);
+
+    let (_, jobs) = server.json("GET", "/jobs", None);
+    assert_eq!(jobs.as_array().map(Vec::len), Some(1));
+    assert_eq!(jobs[0]["id"], queued);
+}
+
+#[test]
+fn bad_requests_are_rejected() {
+    let server = TestServer::start();
+
+    let (status, body) = server.json("POST", "/jobs", Some(&json!({ "scenes": {} })));
+    assert_eq!(status, 400, "{body}");
+
+    let (status, body) = server.json("POST", "/jobs", Some(&json!({ "manifest": "(scenes: oops)" })));
+    assert_eq!(status, 400, "{body}");
+    assert!(
+        body["error"]
+            .as_str()
+            .is_some_and(|error| error.starts_with("Invalid manifest"))
+    );
+
+    let (status, _) = server.json("GET", "/jobs/42", None);
+    assert_eq!(status, 404);
+    let (status, _) = server.json("GET", "/jobs/not-a-number", None);
+    assert_eq!(status, 404);
+    let (status, _) = server.json("GET", "/renders", None);
+    assert_eq!(status, 404);
+
+    let (status, _) = server.json("PUT", "/jobs", None);
+    assert_eq!(status, 405);
+    let (status, _) = server.json("PATCH", "/jobs/1", None);
+    assert_eq!(status, 405);
+
+    let (status, _) = server.raw(b"garbage\r\n\r\n");
+    assert_eq!(status, 400);
+
+    // Exactly the line limit with no line ending, so the server reads everything that was sent.
+    let endless_line = format!("GET /{}", "a".repeat(8 * 1024 - 5));
+    let (status, body) = server.raw(endless_line.as_bytes());
+    assert_eq!(status, 400, "{}", String::from_utf8_lossy(&body));
+}
@@ -0,0 +1,45 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CropWindow {
+    Pixels { min: [usize; 2], max: [usize; 2] },
+    Normalised { min: [f32; 2], max: [f32; 2] },
+}
+
+impl CropWindow {
+    #[must_use]
+    #[inline]
+    pub fn pixel_bounds(&self, resolution: [usize; 2]) -> ([usize; 2], [usize; 2]) {
+        self.checked_pixel_bounds(resolution)
+            .expect("Crop window must cover at least one pixel of the image")
+    }
+
+    // `None` when the window is empty, inverted or lies entirely outside the image.
+    #[must_use]
+    #[inline]
+    pub fn checked_pixel_bounds(&self, resolution: [usize; 2]) -> Option<([usize; 2], [usize; 2])> {
+        let (min, max) = match *self {
+            Self::Pixels { min, max } => (min, max),
+            Self::Normalised { min, max } => (
+                [0, 1].map(|axis| (min[axis].clamp(0.0, 1.0) * resolution[axis] as f32).floor() as usize),
+                [0, 1].map(|axis| (max[axis].clamp(0.0, 1.0) * resolution[axis] as f32).ceil() as usize),
+            ),
+        };
+
+        let max = [max[0].min(resolution[0]), max[1].min(resolution[1])];
+
+        (min[0] < max[0] && min[1] < max[1]).then_some((min, max))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CropOutput {
+    #[default]
+    Cropped,
+    Full,
+    Paste,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crop {
+    pub window: CropWindow,
+    pub output: CropOutput,
+}
@@ -1,9 +1,44 @@
 use antler_colour::Rgba;
 
+use crate::crop::{Crop, CropOutput};
+
 #[derive(Clone)]
 pub struct ImageSettings {
     pub background: Rgba,
     pub resolution: [usize; 2],
     pub tile_size: [usize; 2],
     pub super_samples: usize,
+    pub crop: Option<Crop>,
+    pub stream: bool,
+}
+
+impl ImageSettings {
+    #[must_use]
+    #[inline]
+    pub fn region(&self) -> ([usize; 2], [usize; 2]) {
+        self.crop.map_or(([0, 0], self.resolution), |crop| {
+            crop.window.pixel_bounds(self.resolution)
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn output_origin(&self) -> [usize; 2] {
+        match self.crop {
+            Some(crop) if crop.output != CropOutput::Full => self.region().0,
+            _ => [0, 0],
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn output_resolution(&self) -> [usize; 2] {
+        match self.crop {
+            Some(crop) if crop.output != CropOutput::Full => {
+                let (min, max) = self.region();
+                [max[0] - min[0], max[1] - min[1]]
+            }
+            _ => self.resolution,
+        }
+    }
 }
@@ -1,9 +1,13 @@
+mod crop;
 mod image_settings;
 mod lighting_settings;
 mod occlusion_settings;
 mod probe_settings;
+mod sequence;
 
+pub use crop::{Crop, CropOutput, CropWindow};
 pub use image_settings::ImageSettings;
 pub use lighting_settings::LightingSettings;
 pub use occlusion_settings::OcclusionSettings;
 pub use probe_settings::ProbeSettings;
+pub use sequence::{ContactSheet, Sequence, SequenceAnimation};
@@ -0,0 +1,36 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceAnimation {
+    pub fps: u16,
+    pub plays: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContactSheet {
+    pub columns: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sequence {
+    pub animation: Option<SequenceAnimation>,
+    pub contact_sheet: Option<ContactSheet>,
+}
+
+impl Sequence {
+    #[must_use]
+    #[inline]
+    pub fn frame_name(name: &str, frame: usize) -> String {
+        format!("{name}-{frame:04}")
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn animation_name(name: &str) -> String {
+        format!("{name}-animation")
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contact_sheet_name(name: &str) -> String {
+        format!("{name}-contact-sheet")
+    }
+}
@@ -9,6 +9,7 @@ mod normal;
 mod shader;
 mod solid;
 mod textured;
+mod vertex_colour;
 mod wireframe;
 
 pub use angular::Angular;
@@ -22,4 +23,5 @@ pub use normal::Normal;
 pub use shader::Shader;
 pub use solid::Solid;
 pub use textured::Textured;
+pub use vertex_colour::VertexColour;
 pub use wireframe::Wireframe;
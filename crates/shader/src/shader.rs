@@ -5,7 +5,8 @@ use nalgebra::{Unit, Vector3};
 
 use crate::{
     angular::Angular, appearance::Appearance, block::Block, checkerboard::Checkerboard, gradient::Gradient,
-    iridescent::Iridescent, luminous::Luminous, normal::Normal, solid::Solid, textured::Textured, wireframe::Wireframe,
+    iridescent::Iridescent, luminous::Luminous, normal::Normal, solid::Solid, textured::Textured,
+    vertex_colour::VertexColour, wireframe::Wireframe,
 };
 
 pub enum Shader {
@@ -18,6 +19,7 @@ pub enum Shader {
     Normal(Normal),
     Solid(Solid),
     Textured(Textured),
+    VertexColour(VertexColour),
     Wireframe(Wireframe),
 }
 
@@ -34,6 +36,7 @@ impl Appearance for Shader {
             Self::Normal(normal) => normal.colour(direction, contact),
             Self::Solid(solid) => solid.colour(direction, contact),
             Self::Textured(textured) => textured.colour(direction, contact),
+            Self::VertexColour(vertex_colour) => vertex_colour.colour(direction, contact),
             Self::Wireframe(wireframe) => wireframe.colour(direction, contact),
         }
     }
@@ -50,6 +53,7 @@ impl Appearance for Shader {
             Self::Normal(normal) => normal.emitted(contact),
             Self::Solid(solid) => solid.emitted(contact),
             Self::Textured(textured) => textured.emitted(contact),
+            Self::VertexColour(vertex_colour) => vertex_colour.emitted(contact),
             Self::Wireframe(wireframe) => wireframe.emitted(contact),
         }
     }
@@ -66,6 +70,7 @@ impl Appearance for Shader {
             Self::Normal(normal) => normal.shade(ray, contact, light),
             Self::Solid(solid) => solid.shade(ray, contact, light),
             Self::Textured(textured) => textured.shade(ray, contact, light),
+            Self::VertexColour(vertex_colour) => vertex_colour.shade(ray, contact, light),
             Self::Wireframe(wireframe) => wireframe.shade(ray, contact, light),
         }
     }
@@ -134,6 +139,13 @@ impl From<Textured> for Shader {
     }
 }
 
+impl From<VertexColour> for Shader {
+    #[inline]
+    fn from(val: VertexColour) -> Self {
+        Self::VertexColour(val)
+    }
+}
+
 impl From<Wireframe> for Shader {
     #[inline]
     fn from(val: Wireframe) -> Self {
@@ -0,0 +1,36 @@
+use antler_colour::Rgb;
+use antler_geometry::{Contact, Ray};
+use antler_light::LightSample;
+use nalgebra::{Unit, Vector3};
+
+use crate::Appearance;
+
+// Uses colours interpolated from mesh vertices, falling back to a fixed colour where the surface has none.
+pub struct VertexColour {
+    fallback: Rgb,
+}
+
+impl VertexColour {
+    #[must_use]
+    pub const fn new(fallback: Rgb) -> Self {
+        Self { fallback }
+    }
+}
+
+impl Appearance for VertexColour {
+    #[inline]
+    fn colour(&self, _direction: &Unit<Vector3<f32>>, contact: &Contact) -> Rgb {
+        contact.colour.unwrap_or(self.fallback)
+    }
+
+    #[inline]
+    fn emitted(&self, _contact: &Contact) -> Rgb {
+        Rgb::BLACK
+    }
+
+    #[inline]
+    fn shade(&self, ray: &Ray, contact: &Contact, light: &LightSample) -> Rgb {
+        let n_dot_l = contact.normal.dot(&light.direction).max(0.0);
+        self.colour(&ray.direction, contact) * light.radiance * n_dot_l
+    }
+}
@@ -0,0 +1,138 @@
+import math
+import os
+import random
+import subprocess
+import sys
+import tempfile
+import time
+from pathlib import Path
+
+TREE_MESH = "assets/meshes/tree.obj"
+
+
+def instance_transform(rng: random.Random, *, x: float, y: float) -> str:
+    return (
+        f"(translation: ({x:.2f}, {y:.2f}, 0.0), "
+        f"rotation: (0.0, 0.0, {rng.uniform(0.0, 360.0):.1f}), "
+        f"scale: {rng.uniform(0.7, 1.3):.2f})"
+    )
+
+
+def generate_forest(*, count: int, spacing: float, seed: int) -> tuple[str, float]:
+    # The grid starts at the origin, where the template itself stands as the first tree.
+    rng = random.Random(seed)
+    side = math.ceil(math.sqrt(count))
+    extent = side * spacing
+
+    transforms: list[str] = []
+    for i in range(1, count):
+        row, column = divmod(i, side)
+        x = (column + rng.uniform(-0.3, 0.3)) * spacing
+        y = (row + rng.uniform(-0.3, 0.3)) * spacing
+        transforms.append("            " + instance_transform(rng, x=x, y=y))
+
+    return ",\n".join(transforms), extent
+
+
+def forest_manifest(*, output_dir: Path, count: int, spacing: float, seed: int, resolution: int) -> str:
+    transforms, extent = generate_forest(count=count, spacing=spacing, seed=seed)
+    centre = (extent - spacing) * 0.5
+
+    return f"""(
+  assets_dir: "assets",
+  output_dir: "{output_dir.as_posix()}",
+  lighting_settings: (
+    emitted: 1.0,
+    ambient: 1.0,
+    direct: 1.0,
+    indirect: 0.0
+  ),
+  probe_settings: (
+    max_generation: 2,
+    min_weight: 1.0e-2
+  ),
+  scenes: {{
+    "forest": (
+      ambient: 0x333333,
+      skybox: Gradient ( gradient: [0x99BFFF, 0x050514], power: 1.0 ),
+      occlusion: None,
+      lights: [
+        Directional ( direction: (-1.0, -0.5, -1.0), colour: 0xFFFFFF ),
+      ],
+      objects: [
+        (
+          name: Some("tree"),
+          geometry: Mesh ( path: "{TREE_MESH}" ),
+          material: Opaque,
+          shader: Normal
+        ),
+        (
+          geometry: Circle ( centre: ({centre:.1f}, {centre:.1f}, 0.0), radius: {extent:.1f} ),
+          material: Opaque,
+          shader: Checkerboard ( size: {spacing:.1f}, colour_a: 0x445533, colour_b: 0x334422 )
+        )
+      ],
+      instances: [
+        (
+          object: "tree",
+          transforms: [
+{transforms}
+          ]
+        )
+      ],
+      captures: {{
+        "aerial": (
+          camera: Perspective (
+            position: ({centre + extent * 0.6:.1f}, {centre + extent * 0.6:.1f}, {extent * 0.4:.1f}),
+            look_at: ({centre:.1f}, {centre:.1f}, 0.0),
+            vertical_fov: 50.0
+          ),
+          images: {{
+            "forest": (
+              resolution: ({resolution}, {resolution * 3 // 4}),
+              super_samples: 1
+            )
+          }}
+        )
+      }}
+    )
+  }}
+)
+"""
+
+
+def main() -> None:
+    count = int(sys.argv[1]) if len(sys.argv) > 1 else 100_000
+    resolution = int(sys.argv[2]) if len(sys.argv) > 2 else 640
+
+    subprocess.run(["cargo", "build", "--release", "--quiet"], check=True)
+
+    with tempfile.TemporaryDirectory() as directory:
+        manifest_path = Path(directory) / "forest.ron"
+        manifest_path.write_text(
+            forest_manifest(
+                output_dir=Path("output") / "forest",
+                count=count,
+                spacing=6.0,
+                seed=43,
+                resolution=resolution,
+            ),
+            encoding="utf-8",
+        )
+
+        start = time.perf_counter()
+        process = subprocess.Popen(["target/release/antler", "render", str(manifest_path)])
+        _, status, usage = os.wait4(process.pid, 0)
+        elapsed = time.perf_counter() - start
+
+    if os.waitstatus_to_exitcode(status) != 0:
+        sys.exit(f"Render failed with status {os.waitstatus_to_exitcode(status)}")
+
+    # Linux reports the peak resident set size of the render alone in kilobytes.
+    peak_mib = usage.ru_maxrss / 1024.0
+
+    print(f"Rendered {count} instances of {TREE_MESH} in {elapsed:.1f}s with a peak of {peak_mib:.0f} MiB")
+
+
+if __name__ == "__main__":
+    main()